@@ -0,0 +1,181 @@
+use std::{
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait AccumulateStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: Clone,
+{
+    /// Yield a snapshot of all the items seen so far, including the latest one.
+    ///
+    /// Every snapshot is a fresh clone of the accumulated `Vec`, i.e. the cost is `O(n)` per item.
+    /// See [`accumulate_shared`](`AccumulateStreamExt::accumulate_shared`) for a cheaper alternative.
+    fn accumulate_vec(self) -> AccumulateVec<Self, Self::Item> {
+        AccumulateVec::new(self)
+    }
+
+    /// Similar to [`accumulate_vec`](`AccumulateStreamExt::accumulate_vec`) but the snapshots share the storage.
+    ///
+    /// The `Vec` is cloned only if the previous snapshot is still alive when the next item arrives.
+    fn accumulate_shared(self) -> AccumulateShared<Self, Self::Item> {
+        AccumulateShared::new(self)
+    }
+}
+
+/// Stream for [`accumulate_vec`](`AccumulateStreamExt::accumulate_vec`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct AccumulateVec<Stream, Item> {
+    #[pin]
+    inner: Stream,
+
+    acc: Vec<Item>,
+}
+
+/// Stream for [`accumulate_shared`](`AccumulateStreamExt::accumulate_shared`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct AccumulateShared<Stream, Item> {
+    #[pin]
+    inner: Stream,
+
+    acc: Arc<Vec<Item>>,
+}
+
+impl<S> AccumulateVec<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            acc: Vec::new(),
+        }
+    }
+}
+
+impl<S> AccumulateShared<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            acc: Arc::new(Vec::new()),
+        }
+    }
+}
+
+impl<S> Stream for AccumulateVec<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        let item = ready!(this.inner.poll_next(cx));
+        Poll::Ready(item.map(|item| {
+            this.acc.push(item);
+            this.acc.clone()
+        }))
+    }
+}
+
+impl<S> Stream for AccumulateShared<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    type Item = Arc<Vec<S::Item>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        let item = ready!(this.inner.poll_next(cx));
+        Poll::Ready(item.map(|item| {
+            Arc::make_mut(this.acc).push(item);
+            Arc::clone(this.acc)
+        }))
+    }
+}
+
+impl<S> AccumulateStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<()>()
+            .accumulate_vec()
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+        assert!(stream::empty::<()>()
+            .accumulate_shared()
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn vec_snapshots_grow() {
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .accumulate_vec()
+                .collect::<Vec<_>>()
+                .await,
+            vec![vec![1], vec![1, 2], vec![1, 2, 3]]
+        );
+    }
+
+    #[tokio::test]
+    async fn shared_snapshots_grow() {
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .accumulate_shared()
+                .collect::<Vec<_>>()
+                .await,
+            vec![
+                Arc::new(vec![1]),
+                Arc::new(vec![1, 2]),
+                Arc::new(vec![1, 2, 3])
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn shared_storage_is_reused_once_the_snapshot_is_dropped() {
+        let mut accumulated = stream::iter([1, 2]).accumulate_shared();
+
+        let first = accumulated.next().await.unwrap();
+        let first_ptr = Arc::as_ptr(&first);
+        drop(first);
+
+        let second = accumulated.next().await.unwrap();
+        assert_eq!(Arc::as_ptr(&second), first_ptr);
+        assert_eq!(*second, vec![1, 2]);
+    }
+}
@@ -0,0 +1,101 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait ArrivalDeltaPollsStreamExt: Stream + Sized {
+    /// Tag each item with the number of polls made since the previous item was emitted.
+    ///
+    /// The count includes the pending polls as well as the poll that produced the item itself,
+    /// so an item that is ready right away yields `1`. The first item is always tagged with `0`.
+    fn arrival_delta_polls(self) -> ArrivalDeltaPolls<Self> {
+        ArrivalDeltaPolls::new(self)
+    }
+}
+
+/// Stream for [`arrival_delta_polls`](`ArrivalDeltaPollsStreamExt::arrival_delta_polls`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct ArrivalDeltaPolls<Stream> {
+    #[pin]
+    inner: Stream,
+    polls: usize,
+    emitted: bool,
+}
+
+impl<S> ArrivalDeltaPolls<S> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            polls: 0,
+            emitted: false,
+        }
+    }
+}
+
+impl<S> Stream for ArrivalDeltaPolls<S>
+where
+    S: Stream,
+{
+    type Item = (S::Item, usize);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+        *this.polls += 1;
+
+        let item = ready!(this.inner.poll_next(cx));
+        Poll::Ready(item.map(|item| {
+            let delta = if *this.emitted { *this.polls } else { 0 };
+            *this.polls = 0;
+            *this.emitted = true;
+            (item, delta)
+        }))
+    }
+}
+
+impl<S> ArrivalDeltaPollsStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use crate::test_utils::ready_after_n_polls;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<()>()
+            .arrival_delta_polls()
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn ready_items_are_one_poll_apart() {
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .arrival_delta_polls()
+                .collect::<Vec<_>>()
+                .await,
+            vec![(1, 0), (2, 1), (3, 1)]
+        );
+    }
+
+    #[tokio::test]
+    async fn pending_polls_are_counted() {
+        assert_eq!(
+            stream::iter([(1, 2), (2, 0), (3, 3), (4, 1)])
+                .then(|(item, polls)| ready_after_n_polls(item, polls))
+                .arrival_delta_polls()
+                .collect::<Vec<_>>()
+                .await,
+            vec![(1, 0), (2, 1), (3, 4), (4, 2)]
+        );
+    }
+}
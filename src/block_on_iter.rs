@@ -0,0 +1,81 @@
+use std::pin::Pin;
+
+use futures::{Stream, StreamExt};
+use tokio::runtime::Handle;
+
+pub trait BlockOnIterStreamExt: Stream + Sized {
+    /// Turn the stream into a blocking [`Iterator`], driving each `next()` with [`Handle::block_on`].
+    ///
+    /// The iterator must not be used from within an asynchronous context (e.g. from a task running on the
+    /// runtime behind `handle`): `Handle::block_on` panics there, and blocking a runtime thread may deadlock.
+    fn block_on_iter(self, handle: Handle) -> BlockOnIter<Self> {
+        BlockOnIter::new(self, handle)
+    }
+}
+
+/// Iterator for [`block_on_iter`](`BlockOnIterStreamExt::block_on_iter`) method.
+#[derive(Debug)]
+pub struct BlockOnIter<Stream> {
+    inner: Pin<Box<Stream>>,
+    handle: Handle,
+}
+
+impl<S> BlockOnIter<S> {
+    pub fn new(inner: S, handle: Handle) -> Self {
+        Self {
+            inner: Box::pin(inner),
+            handle,
+        }
+    }
+}
+
+impl<S> Iterator for BlockOnIter<S>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.handle.block_on(self.inner.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S> BlockOnIterStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream;
+
+    use crate::expand::ExpandStreamExt;
+
+    use super::*;
+
+    #[test]
+    fn drains_a_finite_stream() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .block_on_iter(runtime.handle().clone())
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn drains_an_expanded_stream() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .chain(stream::pending())
+                .expand()
+                .block_on_iter(runtime.handle().clone())
+                .take(5)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3, 3, 3]
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+use crate::with_high_water::Buffered;
+
+pub trait BufferUntilStreamExt: Stream + Sized {
+    /// Accumulate the items into a batch, emitting it as soon as `f` returns `true` for the batch.
+    ///
+    /// `f` is called after each item is added. A non-empty remainder is emitted when the upstream ends.
+    fn buffer_until<F>(self, f: F) -> BufferUntil<Self, F, Self::Item>
+    where
+        F: FnMut(&[Self::Item]) -> bool,
+    {
+        BufferUntil::new(self, f)
+    }
+}
+
+/// Stream for [`buffer_until`](`BufferUntilStreamExt::buffer_until`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct BufferUntil<Stream, F, Item> {
+    #[pin]
+    inner: Stream,
+    f: F,
+
+    batch: Vec<Item>,
+}
+
+impl<S, F> BufferUntil<S, F, S::Item>
+where
+    S: Stream,
+{
+    pub fn new(inner: S, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            batch: Vec::new(),
+        }
+    }
+}
+
+impl<S, F> Stream for BufferUntil<S, F, S::Item>
+where
+    S: Stream,
+    F: FnMut(&[S::Item]) -> bool,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            let Some(item) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                let rest = std::mem::take(this.batch);
+                break Some(rest).filter(|rest| !rest.is_empty());
+            };
+            this.batch.push(item);
+            if (this.f)(this.batch) {
+                break Some(std::mem::take(this.batch));
+            }
+        })
+    }
+}
+
+impl<S, F, I> Buffered for BufferUntil<S, F, I> {
+    /// Number of items in the batch being accumulated.
+    fn buffered_len(&self) -> usize {
+        self.batch.len()
+    }
+}
+
+impl<S> BufferUntilStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<i32>()
+            .buffer_until(|_| true)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn flushes_once_the_sum_exceeds_the_threshold() {
+        assert_eq!(
+            stream::iter([3, 4, 5, 1, 1, 9, 2, 2])
+                .buffer_until(|batch| batch.iter().sum::<i32>() > 6)
+                .collect::<Vec<_>>()
+                .await,
+            vec![vec![3, 4], vec![5, 1, 1], vec![9], vec![2, 2]]
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait BurstIdStreamExt: Stream + Sized {
+    /// Tag each item with the id of its burst: a run of items the upstream yields without returning `Pending`.
+    ///
+    /// The ids start at `0` and grow by one at each pending boundary between two items,
+    /// no matter how many `Pending`s the boundary consists of.
+    fn burst_id(self) -> BurstId<Self> {
+        BurstId::new(self)
+    }
+}
+
+/// Stream for [`burst_id`](`BurstIdStreamExt::burst_id`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct BurstId<Stream> {
+    #[pin]
+    inner: Stream,
+
+    id: u64,
+    emitted: bool,
+    pending_seen: bool,
+}
+
+impl<S> BurstId<S> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            id: 0,
+            emitted: false,
+            pending_seen: false,
+        }
+    }
+}
+
+impl<S> Stream for BurstId<S>
+where
+    S: Stream,
+{
+    type Item = (u64, S::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        match this.inner.poll_next(cx) {
+            Poll::Pending => {
+                *this.pending_seen = true;
+                Poll::Pending
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Ready(Some(item)) => {
+                if *this.pending_seen && *this.emitted {
+                    *this.id += 1;
+                }
+                *this.pending_seen = false;
+                *this.emitted = true;
+                Poll::Ready(Some((*this.id, item)))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S> BurstIdStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+    use crate::test_utils::ready_after_n_polls;
+
+    #[tokio::test]
+    async fn a_ready_stream_is_a_single_burst() {
+        assert_eq!(
+            stream::iter(['a', 'b'])
+                .burst_id()
+                .collect::<Vec<_>>()
+                .await,
+            vec![(0, 'a'), (0, 'b')]
+        );
+    }
+
+    #[tokio::test]
+    async fn items_of_a_burst_share_the_id() {
+        assert_eq!(
+            stream::iter([vec![1, 2, 3], vec![4], vec![5, 6]])
+                .map(stream::iter)
+                .then(|chunk| ready_after_n_polls(chunk, 2))
+                .flatten()
+                .burst_id()
+                .collect::<Vec<_>>()
+                .await,
+            vec![(0, 1), (0, 2), (0, 3), (1, 4), (2, 5), (2, 6)]
+        );
+    }
+}
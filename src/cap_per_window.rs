@@ -0,0 +1,170 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait CapPerWindowStreamExt: Stream + Sized {
+    /// Emit at most `max` items per sliding window of `window` polls, dropping the excess.
+    ///
+    /// Each call to `poll_next` counts as one poll and polls the upstream at most once. While the cap is reached,
+    /// the incoming items replace each other in a single slot, so the most recent one is emitted once the window allows.
+    /// Meanwhile the stream wakes itself up to let the window slide, and it ends only after the slot is flushed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max` or `window` is zero.
+    fn cap_per_window(self, max: usize, window: usize) -> CapPerWindow<Self, Self::Item> {
+        CapPerWindow::new(self, max, window)
+    }
+}
+
+/// Stream for [`cap_per_window`](`CapPerWindowStreamExt::cap_per_window`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct CapPerWindow<Stream, Item> {
+    #[pin]
+    inner: Stream,
+    max: usize,
+    window: usize,
+
+    tick: usize,
+    emitted_at: VecDeque<usize>,
+    slot: Option<Item>,
+    terminated: bool,
+}
+
+impl<S> CapPerWindow<S, S::Item>
+where
+    S: Stream,
+{
+    pub fn new(inner: S, max: usize, window: usize) -> Self {
+        assert!(max > 0, "the cap must not be zero");
+        assert!(window > 0, "the window must not be empty");
+        Self {
+            inner,
+            max,
+            window,
+            tick: 0,
+            emitted_at: VecDeque::with_capacity(max),
+            slot: None,
+            terminated: false,
+        }
+    }
+}
+
+impl<S> Stream for CapPerWindow<S, S::Item>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        *this.tick += 1;
+        let tick = *this.tick;
+        while this
+            .emitted_at
+            .front()
+            .is_some_and(|at| tick - at >= *this.window)
+        {
+            this.emitted_at.pop_front();
+        }
+        let has_capacity = this.emitted_at.len() < *this.max;
+
+        if has_capacity {
+            if let Some(item) = this.slot.take() {
+                this.emitted_at.push_back(tick);
+                return Poll::Ready(Some(item));
+            }
+        }
+
+        // One upstream poll per call: with capacity, a ready item is emitted right away; without it, the item
+        // takes the slot and the self-wake below comes back for the next one, so an always-ready upstream
+        // cannot keep this poll spinning.
+        if !*this.terminated {
+            match this.inner.as_mut().poll_next(cx) {
+                Poll::Pending => (),
+                Poll::Ready(None) => *this.terminated = true,
+                Poll::Ready(Some(item)) if has_capacity => {
+                    this.emitted_at.push_back(tick);
+                    return Poll::Ready(Some(item));
+                }
+                Poll::Ready(Some(item)) => *this.slot = Some(item),
+            }
+        }
+
+        match (this.slot.is_some(), *this.terminated) {
+            (false, true) => Poll::Ready(None),
+            (false, false) => Poll::Pending,
+            (true, _) => {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<S> CapPerWindowStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+    use crate::{arrival_delta_polls::ArrivalDeltaPollsStreamExt, test_utils::ready_after_n_polls};
+
+    #[tokio::test]
+    async fn excess_is_dropped_in_favour_of_the_latest() {
+        assert_eq!(
+            stream::iter(0..20)
+                .cap_per_window(2, 5)
+                .collect::<Vec<_>>()
+                .await,
+            vec![0, 1, 4, 5, 8, 9, 12, 13, 16, 17, 19]
+        );
+    }
+
+    #[tokio::test]
+    async fn slow_upstream_is_not_capped() {
+        assert_eq!(
+            stream::iter(0..5)
+                .then(|x| ready_after_n_polls(x, 3))
+                .cap_per_window(1, 3)
+                .collect::<Vec<_>>()
+                .await,
+            vec![0, 1, 2, 3, 4]
+        );
+    }
+
+    #[tokio::test]
+    async fn emissions_never_exceed_the_cap_per_window() {
+        let emitted = stream::iter([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]])
+            .map(stream::iter)
+            .then(|chunk| ready_after_n_polls(chunk, 1))
+            .flatten()
+            .cap_per_window(1, 3)
+            .arrival_delta_polls()
+            .collect::<Vec<_>>()
+            .await;
+
+        assert!(emitted.iter().skip(1).all(|(_, delta)| *delta >= 3));
+        assert_eq!(emitted.last().map(|(item, _)| *item), Some(12));
+    }
+
+    #[tokio::test]
+    async fn an_always_ready_upstream_does_not_hang() {
+        let emitted = stream::repeat(7)
+            .cap_per_window(1, 3)
+            .arrival_delta_polls()
+            .take(3)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(emitted.len(), 3);
+        assert!(emitted.iter().skip(1).all(|(_, delta)| *delta >= 3));
+    }
+}
@@ -0,0 +1,199 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+use crate::with_high_water::Buffered;
+
+pub trait ChunksStridedStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: Clone,
+{
+    /// Yield windows of `size` items, each starting `step` items after the previous one.
+    ///
+    /// `step < size` makes the windows overlap, `step == size` makes them disjoint and `step > size` skips
+    /// the items in between. A window that is still incomplete when the upstream ends is handled as per `partial`;
+    /// it is never emitted if all its items have already been emitted as part of the previous window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `size` or `step` is zero.
+    fn chunks_strided(
+        self,
+        size: usize,
+        step: usize,
+        partial: PartialWindow,
+    ) -> ChunksStrided<Self, Self::Item> {
+        ChunksStrided::new(self, size, step, partial)
+    }
+}
+
+/// What [`ChunksStrided`] does with the last, incomplete, window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartialWindow {
+    Emit,
+    Drop,
+}
+
+/// Stream for [`chunks_strided`](`ChunksStridedStreamExt::chunks_strided`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct ChunksStrided<Stream, Item> {
+    #[pin]
+    inner: Stream,
+    size: usize,
+    step: usize,
+    partial: PartialWindow,
+
+    window: Vec<Item>,
+    fresh: usize,
+    skip: usize,
+    done: bool,
+}
+
+impl<S> ChunksStrided<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    pub fn new(inner: S, size: usize, step: usize, partial: PartialWindow) -> Self {
+        assert!(size > 0, "the window must not be empty");
+        assert!(step > 0, "the step must not be zero");
+        Self {
+            inner,
+            size,
+            step,
+            partial,
+            window: Vec::with_capacity(size),
+            fresh: 0,
+            skip: 0,
+            done: false,
+        }
+    }
+}
+
+impl<S> Stream for ChunksStrided<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        Poll::Ready(loop {
+            let Some(item) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                *this.done = true;
+                let emit = *this.partial == PartialWindow::Emit && *this.fresh > 0;
+                let window = std::mem::take(this.window);
+                break emit.then_some(window);
+            };
+
+            if *this.skip > 0 {
+                *this.skip -= 1;
+                continue;
+            }
+
+            this.window.push(item);
+            *this.fresh += 1;
+            if this.window.len() < *this.size {
+                continue;
+            }
+
+            let window = if *this.step < *this.size {
+                let window = this.window.clone();
+                this.window.drain(..*this.step);
+                window
+            } else {
+                *this.skip = *this.step - *this.size;
+                std::mem::replace(this.window, Vec::with_capacity(*this.size))
+            };
+            *this.fresh = 0;
+            break Some(window);
+        })
+    }
+}
+
+impl<S, I> Buffered for ChunksStrided<S, I> {
+    /// Number of items held in the current window.
+    fn buffered_len(&self) -> usize {
+        self.window.len()
+    }
+}
+
+impl<S> ChunksStridedStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    async fn strided(
+        items: usize,
+        size: usize,
+        step: usize,
+        partial: PartialWindow,
+    ) -> Vec<Vec<usize>> {
+        stream::iter(0..items)
+            .chunks_strided(size, step, partial)
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(strided(0, 3, 2, PartialWindow::Emit).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn overlapping_windows() {
+        assert_eq!(
+            strided(6, 3, 2, PartialWindow::Emit).await,
+            vec![vec![0, 1, 2], vec![2, 3, 4], vec![4, 5]]
+        );
+        assert_eq!(
+            strided(6, 3, 2, PartialWindow::Drop).await,
+            vec![vec![0, 1, 2], vec![2, 3, 4]]
+        );
+    }
+
+    #[tokio::test]
+    async fn disjoint_windows() {
+        assert_eq!(
+            strided(7, 3, 3, PartialWindow::Emit).await,
+            vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]
+        );
+    }
+
+    #[tokio::test]
+    async fn sparse_windows() {
+        assert_eq!(
+            strided(8, 2, 3, PartialWindow::Emit).await,
+            vec![vec![0, 1], vec![3, 4], vec![6, 7]]
+        );
+    }
+
+    #[tokio::test]
+    async fn already_emitted_tail_is_not_repeated() {
+        assert_eq!(
+            strided(5, 3, 2, PartialWindow::Emit).await,
+            vec![vec![0, 1, 2], vec![2, 3, 4]]
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use std::{
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait CollectArrayStreamExt: Stream + Sized {
+    /// Collect the first `N` items into an array, yielded as [`Head::Array`], then pass the rest through
+    /// as [`Head::Rest`].
+    ///
+    /// If the upstream ends before `N` items arrive, the stream ends without yielding the partial array.
+    fn collect_array<const N: usize>(self) -> CollectArray<Self, Self::Item, N> {
+        CollectArray::new(self)
+    }
+}
+
+/// Either the collected head or an item that follows it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Head<T, const N: usize> {
+    Array([T; N]),
+    Rest(T),
+}
+
+/// Stream for [`collect_array`](`CollectArrayStreamExt::collect_array`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct CollectArray<Stream, Item, const N: usize> {
+    #[pin]
+    inner: Stream,
+
+    head: Option<Vec<Item>>,
+}
+
+impl<S, I, const N: usize> CollectArray<S, I, N> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            head: Some(Vec::with_capacity(N)),
+        }
+    }
+}
+
+impl<S, const N: usize> Stream for CollectArray<S, S::Item, N>
+where
+    S: Stream,
+{
+    type Item = Head<S::Item, N>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            let Some(head) = this.head else {
+                break ready!(this.inner.as_mut().poll_next(cx)).map(Head::Rest);
+            };
+            if head.len() == N {
+                let Ok(array) = mem::take(head).try_into() else {
+                    unreachable!("the head has exactly N items")
+                };
+                *this.head = None;
+                break Some(Head::Array(array));
+            }
+            let Some(item) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                head.clear();
+                break None;
+            };
+            head.push(item);
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        match &self.head {
+            None => (lower, upper),
+            Some(head) => {
+                let missing = N - head.len();
+                let rest = |n: usize| n.checked_sub(missing).map_or(0, |rest| rest + 1);
+                (rest(lower), upper.map(rest))
+            }
+        }
+    }
+}
+
+impl<S> CollectArrayStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn the_head_then_the_rest() {
+        assert_eq!(
+            stream::iter(0..5)
+                .collect_array::<2>()
+                .collect::<Vec<_>>()
+                .await,
+            vec![
+                Head::Array([0, 1]),
+                Head::Rest(2),
+                Head::Rest(3),
+                Head::Rest(4),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn a_short_stream_yields_nothing() {
+        assert!(stream::iter(0..2)
+            .collect_array::<3>()
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn an_empty_head_is_yielded_right_away() {
+        assert_eq!(
+            stream::iter(0..1)
+                .collect_array::<0>()
+                .collect::<Vec<_>>()
+                .await,
+            vec![Head::Array([]), Head::Rest(0)]
+        );
+    }
+
+    #[test]
+    fn size_hint_counts_the_head_as_one_item() {
+        assert_eq!(
+            stream::iter(0..5).collect_array::<2>().size_hint(),
+            (4, Some(4))
+        );
+        assert_eq!(
+            stream::iter(0..1).collect_array::<2>().size_hint(),
+            (0, Some(0))
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{stream::FusedStream, Stream};
+
+use crate::drain;
+
+pub trait CombineLatestStreamExt: Stream + Sized {
+    /// Pair the latest items of both sides whenever either side yields, once both sides have yielded.
+    ///
+    /// Both sides are polled on every poll; if both yield at once, a single pair with both fresh items is emitted.
+    /// A side that has ended keeps contributing its last item while the other side drives the emissions.
+    /// Until both sides have yielded, the items of the first one are drained a bounded number per poll.
+    /// The stream ends once both sides have ended, or once either side has ended without yielding anything.
+    fn combine_latest<R>(self, right: R) -> CombineLatest<Self, R, Self::Item, R::Item>
+    where
+        R: Stream,
+        Self::Item: Clone,
+        R::Item: Clone,
+    {
+        CombineLatest::new(self, right)
+    }
+}
+
+/// Stream for [`combine_latest`](`CombineLatestStreamExt::combine_latest`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct CombineLatest<L, R, LI, RI> {
+    #[pin]
+    left: L,
+    #[pin]
+    right: R,
+
+    left_latest: Option<LI>,
+    right_latest: Option<RI>,
+    left_done: bool,
+    right_done: bool,
+}
+
+impl<L, R> CombineLatest<L, R, L::Item, R::Item>
+where
+    L: Stream,
+    R: Stream,
+{
+    pub fn new(left: L, right: R) -> Self {
+        Self {
+            left,
+            right,
+            left_latest: None,
+            right_latest: None,
+            left_done: false,
+            right_done: false,
+        }
+    }
+}
+
+impl<L, R> Stream for CombineLatest<L, R, L::Item, R::Item>
+where
+    L: Stream,
+    R: Stream,
+    L::Item: Clone,
+    R::Item: Clone,
+{
+    type Item = (L::Item, R::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        let mut budget = drain::BUDGET;
+        Poll::Ready(loop {
+            if budget == 0 {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            budget -= 1;
+
+            let mut updated = false;
+
+            if !*this.left_done {
+                match this.left.as_mut().poll_next(cx) {
+                    Poll::Pending => (),
+                    Poll::Ready(None) => *this.left_done = true,
+                    Poll::Ready(Some(item)) => {
+                        *this.left_latest = Some(item);
+                        updated = true;
+                    }
+                }
+            }
+            if !*this.right_done {
+                match this.right.as_mut().poll_next(cx) {
+                    Poll::Pending => (),
+                    Poll::Ready(None) => *this.right_done = true,
+                    Poll::Ready(Some(item)) => {
+                        *this.right_latest = Some(item);
+                        updated = true;
+                    }
+                }
+            }
+
+            let left_dead = *this.left_done && this.left_latest.is_none();
+            let right_dead = *this.right_done && this.right_latest.is_none();
+            if (*this.left_done && *this.right_done) || left_dead || right_dead {
+                *this.left_done = true;
+                *this.right_done = true;
+                *this.left_latest = None;
+                *this.right_latest = None;
+                break None;
+            }
+
+            if !updated {
+                return Poll::Pending;
+            }
+
+            if let (Some(l), Some(r)) = (this.left_latest.as_ref(), this.right_latest.as_ref()) {
+                break Some((l.clone(), r.clone()));
+            }
+        })
+    }
+}
+
+impl<L, R> FusedStream for CombineLatest<L, R, L::Item, R::Item>
+where
+    L: Stream,
+    R: Stream,
+    L::Item: Clone,
+    R::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.left_done && self.right_done
+    }
+}
+
+impl<S> CombineLatestStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{channel::mpsc, poll, stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn nothing_is_emitted_until_both_sides_yield() {
+        let (left_tx, left_rx) = mpsc::unbounded();
+        let (right_tx, right_rx) = mpsc::unbounded();
+        let mut combined = left_rx.combine_latest(right_rx);
+
+        left_tx.unbounded_send(1).unwrap();
+        assert_eq!(poll!(combined.next()), Poll::Pending);
+        left_tx.unbounded_send(2).unwrap();
+        assert_eq!(poll!(combined.next()), Poll::Pending);
+
+        right_tx.unbounded_send('a').unwrap();
+        assert_eq!(poll!(combined.next()), Poll::Ready(Some((2, 'a'))));
+
+        left_tx.unbounded_send(3).unwrap();
+        assert_eq!(poll!(combined.next()), Poll::Ready(Some((3, 'a'))));
+        right_tx.unbounded_send('b').unwrap();
+        assert_eq!(poll!(combined.next()), Poll::Ready(Some((3, 'b'))));
+        assert_eq!(poll!(combined.next()), Poll::Pending);
+    }
+
+    #[tokio::test]
+    async fn an_ended_side_keeps_its_last_item() {
+        let (left_tx, left_rx) = mpsc::unbounded();
+        let (right_tx, right_rx) = mpsc::unbounded();
+        let mut combined = left_rx.combine_latest(right_rx);
+
+        left_tx.unbounded_send(1).unwrap();
+        right_tx.unbounded_send('a').unwrap();
+        drop(right_tx);
+        assert_eq!(poll!(combined.next()), Poll::Ready(Some((1, 'a'))));
+
+        left_tx.unbounded_send(2).unwrap();
+        assert_eq!(poll!(combined.next()), Poll::Ready(Some((2, 'a'))));
+        left_tx.unbounded_send(3).unwrap();
+        assert_eq!(poll!(combined.next()), Poll::Ready(Some((3, 'a'))));
+        assert!(!combined.is_terminated());
+
+        drop(left_tx);
+        assert_eq!(poll!(combined.next()), Poll::Ready(None));
+        assert!(combined.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn a_side_ending_empty_ends_the_stream() {
+        assert!(stream::iter([1, 2, 3])
+            .combine_latest(stream::empty::<()>())
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn an_always_ready_side_does_not_hang_while_the_other_is_silent() {
+        let mut combined = stream::repeat(1).combine_latest(stream::pending::<i32>());
+        assert_eq!(poll!(combined.next()), Poll::Pending);
+    }
+}
@@ -0,0 +1,208 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+use crate::drain;
+
+pub trait CombineLatestFreshStreamExt: Stream + Sized {
+    /// Pair the latest items of both sides whenever either side yields, skipping the stale pairs.
+    ///
+    /// A side's age is the number of `poll_next` calls since it last yielded. A pair is emitted only
+    /// if neither side is older than `max_lag_polls`; a run of stale updates is suppressed a bounded number
+    /// of updates per poll. The stream ends once both sides have ended, or once either side has ended
+    /// without yielding anything.
+    fn combine_latest_fresh<R>(
+        self,
+        right: R,
+        max_lag_polls: usize,
+    ) -> CombineLatestFresh<Self, R, Self::Item, R::Item>
+    where
+        R: Stream,
+        Self::Item: Clone,
+        R::Item: Clone,
+    {
+        CombineLatestFresh::new(self, right, max_lag_polls)
+    }
+}
+
+/// Stream for [`combine_latest_fresh`](`CombineLatestFreshStreamExt::combine_latest_fresh`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct CombineLatestFresh<L, R, LI, RI> {
+    #[pin]
+    left: L,
+    #[pin]
+    right: R,
+    max_lag_polls: usize,
+
+    left_latest: Option<LI>,
+    right_latest: Option<RI>,
+    left_age: usize,
+    right_age: usize,
+    left_done: bool,
+    right_done: bool,
+}
+
+impl<L, R> CombineLatestFresh<L, R, L::Item, R::Item>
+where
+    L: Stream,
+    R: Stream,
+{
+    pub fn new(left: L, right: R, max_lag_polls: usize) -> Self {
+        Self {
+            left,
+            right,
+            max_lag_polls,
+            left_latest: None,
+            right_latest: None,
+            left_age: 0,
+            right_age: 0,
+            left_done: false,
+            right_done: false,
+        }
+    }
+}
+
+impl<L, R> Stream for CombineLatestFresh<L, R, L::Item, R::Item>
+where
+    L: Stream,
+    R: Stream,
+    L::Item: Clone,
+    R::Item: Clone,
+{
+    type Item = (L::Item, R::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        *this.left_age = this.left_age.saturating_add(1);
+        *this.right_age = this.right_age.saturating_add(1);
+
+        let mut budget = drain::BUDGET;
+        Poll::Ready(loop {
+            if budget == 0 {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            budget -= 1;
+
+            let mut updated = false;
+
+            if !*this.left_done {
+                match this.left.as_mut().poll_next(cx) {
+                    Poll::Pending => (),
+                    Poll::Ready(None) => *this.left_done = true,
+                    Poll::Ready(Some(item)) => {
+                        *this.left_latest = Some(item);
+                        *this.left_age = 0;
+                        updated = true;
+                    }
+                }
+            }
+            if !*this.right_done {
+                match this.right.as_mut().poll_next(cx) {
+                    Poll::Pending => (),
+                    Poll::Ready(None) => *this.right_done = true,
+                    Poll::Ready(Some(item)) => {
+                        *this.right_latest = Some(item);
+                        *this.right_age = 0;
+                        updated = true;
+                    }
+                }
+            }
+
+            let left_dead = *this.left_done && this.left_latest.is_none();
+            let right_dead = *this.right_done && this.right_latest.is_none();
+            if (*this.left_done && *this.right_done) || left_dead || right_dead {
+                *this.left_done = true;
+                *this.right_done = true;
+                break None;
+            }
+
+            if !updated {
+                return Poll::Pending;
+            }
+
+            if *this.left_age <= *this.max_lag_polls && *this.right_age <= *this.max_lag_polls {
+                if let (Some(l), Some(r)) = (this.left_latest.as_ref(), this.right_latest.as_ref())
+                {
+                    break Some((l.clone(), r.clone()));
+                }
+            }
+        })
+    }
+}
+
+impl<S> CombineLatestFreshStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+    use crate::test_utils::ready_after_n_polls;
+
+    #[tokio::test]
+    async fn empty_side_ends_the_stream() {
+        assert!(stream::iter([1, 2, 3])
+            .combine_latest_fresh(stream::empty::<i32>(), 10)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn every_update_is_paired_while_fresh() {
+        let left = stream::iter(1..=3).then(|x| ready_after_n_polls(x, 1));
+        let right = stream::iter([10, 20, 30]).then(|x| ready_after_n_polls(x, 1));
+
+        assert_eq!(
+            left.combine_latest_fresh(right, 2)
+                .collect::<Vec<_>>()
+                .await,
+            vec![(1, 10), (2, 20), (3, 30)]
+        );
+    }
+
+    #[tokio::test]
+    async fn stale_pairs_are_suppressed() {
+        let left = stream::iter(1..=5).then(|x| ready_after_n_polls(x, 1));
+        let right = stream::iter([10]);
+
+        assert_eq!(
+            left.combine_latest_fresh(right, 2)
+                .collect::<Vec<_>>()
+                .await,
+            vec![(1, 10), (2, 10)]
+        );
+    }
+
+    #[tokio::test]
+    async fn a_larger_lag_allows_more_pairs() {
+        let left = stream::iter(1..=5).then(|x| ready_after_n_polls(x, 1));
+        let right = stream::iter([10]);
+
+        assert_eq!(
+            left.combine_latest_fresh(right, 4)
+                .collect::<Vec<_>>()
+                .await,
+            vec![(1, 10), (2, 10), (3, 10)]
+        );
+    }
+
+    #[tokio::test]
+    async fn an_always_ready_side_does_not_hang_a_stale_pair() {
+        use futures::poll;
+
+        let mut combined =
+            stream::repeat(1).combine_latest_fresh(stream::iter([10]).chain(stream::pending()), 2);
+
+        for _ in 0..3 {
+            assert_eq!(poll!(combined.next()), Poll::Ready(Some((1, 10))));
+        }
+        assert_eq!(poll!(combined.next()), Poll::Pending);
+    }
+}
@@ -0,0 +1,176 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{stream::FusedStream, Stream};
+
+use crate::drain;
+
+pub trait DebounceStreamExt: Stream + Sized {
+    /// Emit an item only once the upstream has stayed quiet for a delay made by `delay`.
+    ///
+    /// Each upstream item replaces the held one and restarts the delay, so only the last item of a burst
+    /// is emitted; the upstream is drained a bounded number of items per poll. When the upstream ends,
+    /// the held item is emitted right away. Being generic over the delay, the adapter is not tied
+    /// to a runtime: with tokio, pass `|| tokio::time::sleep(period)`.
+    fn debounce<F, D>(self, delay: F) -> Debounce<Self, F, D, Self::Item>
+    where
+        F: FnMut() -> D,
+        D: Future,
+    {
+        Debounce::new(self, delay)
+    }
+}
+
+/// Stream for [`debounce`](`DebounceStreamExt::debounce`) method.
+#[derive(Debug)]
+#[pin_project::pin_project]
+pub struct Debounce<Stream, F, D, Item> {
+    #[pin]
+    inner: Stream,
+    make_delay: F,
+
+    #[pin]
+    delay: Option<D>,
+    held: Option<Item>,
+    inner_done: bool,
+}
+
+impl<S, F, D, I> Debounce<S, F, D, I> {
+    pub fn new(inner: S, make_delay: F) -> Self {
+        Self {
+            inner,
+            make_delay,
+            delay: None,
+            held: None,
+            inner_done: false,
+        }
+    }
+}
+
+impl<S, F, D> Stream for Debounce<S, F, D, S::Item>
+where
+    S: Stream,
+    F: FnMut() -> D,
+    D: Future,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        let mut budget = drain::BUDGET;
+        while !*this.inner_done {
+            if budget == 0 {
+                cx.waker().wake_by_ref();
+                break;
+            }
+            budget -= 1;
+            match this.inner.as_mut().poll_next(cx) {
+                Poll::Pending => break,
+                Poll::Ready(None) => *this.inner_done = true,
+                Poll::Ready(Some(item)) => {
+                    *this.held = Some(item);
+                    this.delay.set(Some((this.make_delay)()));
+                }
+            }
+        }
+
+        if *this.inner_done {
+            this.delay.set(None);
+            return Poll::Ready(this.held.take());
+        }
+
+        let Some(delay) = this.delay.as_mut().as_pin_mut() else {
+            return Poll::Pending;
+        };
+        match delay.poll(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(_) => {
+                this.delay.set(None);
+                Poll::Ready(Some(
+                    this.held.take().expect("an item is held while delaying"),
+                ))
+            }
+        }
+    }
+}
+
+impl<S, F, D> FusedStream for Debounce<S, F, D, S::Item>
+where
+    S: Stream,
+    F: FnMut() -> D,
+    D: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner_done && self.held.is_none()
+    }
+}
+
+impl<S> DebounceStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{channel::mpsc, poll, stream, StreamExt};
+
+    use super::*;
+    use crate::test_utils::ManualClock;
+
+    #[tokio::test]
+    async fn only_the_last_item_of_a_burst_is_emitted() {
+        let clock = ManualClock::default();
+        let (tx, rx) = mpsc::unbounded();
+        let mut debounced = rx.debounce(|| clock.delay(10));
+
+        tx.unbounded_send(1).unwrap();
+        tx.unbounded_send(2).unwrap();
+        assert_eq!(poll!(debounced.next()), Poll::Pending);
+        clock.advance(5);
+        tx.unbounded_send(3).unwrap();
+        assert_eq!(poll!(debounced.next()), Poll::Pending);
+
+        clock.advance(9);
+        assert_eq!(poll!(debounced.next()), Poll::Pending);
+        clock.advance(1);
+        assert_eq!(poll!(debounced.next()), Poll::Ready(Some(3)));
+        assert_eq!(poll!(debounced.next()), Poll::Pending);
+
+        tx.unbounded_send(4).unwrap();
+        assert_eq!(poll!(debounced.next()), Poll::Pending);
+        clock.advance(10);
+        assert_eq!(poll!(debounced.next()), Poll::Ready(Some(4)));
+    }
+
+    #[tokio::test]
+    async fn the_held_item_is_flushed_at_the_end() {
+        let clock = ManualClock::default();
+        let (tx, rx) = mpsc::unbounded();
+        let mut debounced = rx.debounce(|| clock.delay(10));
+
+        tx.unbounded_send(1).unwrap();
+        assert_eq!(poll!(debounced.next()), Poll::Pending);
+        drop(tx);
+        assert_eq!(poll!(debounced.next()), Poll::Ready(Some(1)));
+        assert_eq!(poll!(debounced.next()), Poll::Ready(None));
+        assert!(debounced.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn an_empty_stream_ends_right_away() {
+        let clock = ManualClock::default();
+        assert!(stream::empty::<()>()
+            .debounce(|| clock.delay(10))
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn an_always_ready_upstream_is_drained_in_bounded_steps() {
+        let clock = ManualClock::default();
+        let mut debounced = stream::repeat(1).debounce(|| clock.delay(10));
+        assert_eq!(poll!(debounced.next()), Poll::Pending);
+    }
+}
@@ -0,0 +1,198 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+use crate::drain;
+
+pub trait DebouncedDistinctPollsStreamExt: Stream + Sized {
+    /// Emit the latest item once the upstream has been pending for `quiet` polls in a row,
+    /// but only if its key differs from the key of the previously emitted item.
+    ///
+    /// While an item is held, the stream wakes itself up so that the quiet polls keep coming.
+    /// The upstream is drained a bounded number of items per poll, which does not count as a quiet poll.
+    /// When the upstream ends, the held item is flushed (subject to the same key check).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `quiet` is zero.
+    fn debounced_distinct_polls<F, Key>(
+        self,
+        quiet: usize,
+        key: F,
+    ) -> DebouncedDistinctPolls<Self, F, Self::Item, Key>
+    where
+        F: FnMut(&Self::Item) -> Key,
+        Key: PartialEq,
+    {
+        DebouncedDistinctPolls::new(self, quiet, key)
+    }
+}
+
+/// Stream for [`debounced_distinct_polls`](`DebouncedDistinctPollsStreamExt::debounced_distinct_polls`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct DebouncedDistinctPolls<Stream, F, Item, Key> {
+    #[pin]
+    inner: Stream,
+    quiet: usize,
+    key: F,
+
+    held: Option<(Item, Key)>,
+    quiet_polls: usize,
+    last_key: Option<Key>,
+    terminated: bool,
+}
+
+impl<S, F, Key> DebouncedDistinctPolls<S, F, S::Item, Key>
+where
+    S: Stream,
+{
+    pub fn new(inner: S, quiet: usize, key: F) -> Self {
+        assert!(quiet > 0, "the quiet period must not be zero");
+        Self {
+            inner,
+            quiet,
+            key,
+            held: None,
+            quiet_polls: 0,
+            last_key: None,
+            terminated: false,
+        }
+    }
+}
+
+fn release<I, K: PartialEq>(held: &mut Option<(I, K)>, last_key: &mut Option<K>) -> Option<I> {
+    let (item, key) = held.take()?;
+    if last_key.as_ref() == Some(&key) {
+        return None;
+    }
+    *last_key = Some(key);
+    Some(item)
+}
+
+impl<S, F, Key> Stream for DebouncedDistinctPolls<S, F, S::Item, Key>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> Key,
+    Key: PartialEq,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        let mut budget = drain::BUDGET;
+        loop {
+            if budget == 0 {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            budget -= 1;
+
+            if *this.terminated {
+                return Poll::Ready(release(this.held, this.last_key));
+            }
+
+            match this.inner.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    let key = (this.key)(&item);
+                    *this.held = Some((item, key));
+                    *this.quiet_polls = 0;
+                }
+                Poll::Ready(None) => *this.terminated = true,
+                Poll::Pending if this.held.is_none() => return Poll::Pending,
+                Poll::Pending => {
+                    *this.quiet_polls += 1;
+                    if *this.quiet_polls < *this.quiet {
+                        cx.waker().wake_by_ref();
+                        return Poll::Pending;
+                    }
+                    if let Some(item) = release(this.held, this.last_key) {
+                        return Poll::Ready(Some(item));
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<S> DebouncedDistinctPollsStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+    use crate::test_utils::ready_after_n_polls;
+
+    fn bursts<const N: usize>(bursts: [Vec<i32>; N], gap: usize) -> impl Stream<Item = i32> {
+        stream::iter(bursts)
+            .map(stream::iter)
+            .then(move |burst| ready_after_n_polls(burst, gap))
+            .flatten()
+    }
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<i32>()
+            .debounced_distinct_polls(2, |x| *x)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn only_distinct_stabilized_values_are_emitted() {
+        assert_eq!(
+            bursts(
+                [vec![1, 2, 3], vec![3, 3], vec![4], vec![1, 4], vec![4, 5]],
+                3
+            )
+            .debounced_distinct_polls(2, |x| *x)
+            .collect::<Vec<_>>()
+            .await,
+            vec![3, 4, 5]
+        );
+    }
+
+    #[tokio::test]
+    async fn short_gaps_do_not_end_the_burst() {
+        assert_eq!(
+            bursts([vec![1, 2], vec![3], vec![4, 5]], 1)
+                .debounced_distinct_polls(3, |x| *x)
+                .collect::<Vec<_>>()
+                .await,
+            vec![5]
+        );
+    }
+
+    #[tokio::test]
+    async fn the_key_decides_what_is_distinct() {
+        assert_eq!(
+            bursts([vec![10, 12], vec![15], vec![21, 29], vec![20]], 2)
+                .debounced_distinct_polls(2, |x| *x / 10)
+                .collect::<Vec<_>>()
+                .await,
+            vec![12, 29]
+        );
+    }
+
+    #[tokio::test]
+    async fn an_always_ready_upstream_is_drained_in_bounded_steps() {
+        use futures::poll;
+
+        let mut debounced = stream::repeat(1).debounced_distinct_polls(1, |n| *n);
+        assert_eq!(poll!(debounced.next()), Poll::Pending);
+
+        assert_eq!(
+            stream::iter(0..100)
+                .debounced_distinct_polls(1, |n| *n)
+                .collect::<Vec<_>>()
+                .await,
+            vec![99]
+        );
+    }
+}
@@ -0,0 +1,360 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{Stream, TryStream};
+
+pub trait DedupStreamExt: Stream + Sized {
+    /// Drop each item equal to the last emitted one, forwarding only the changes.
+    fn dedup(self) -> Dedup<Self, Self::Item>
+    where
+        Self::Item: PartialEq + Clone,
+    {
+        Dedup::new(self)
+    }
+
+    /// Drop each item whose key, as computed with `key_fn`, equals the key of the last emitted item.
+    ///
+    /// Only the keys are compared and retained, so the items need not be `PartialEq` nor `Clone`.
+    fn dedup_by_key<K, F>(self, key_fn: F) -> DedupByKey<Self, F, K>
+    where
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq + Clone,
+    {
+        DedupByKey::new(self, key_fn)
+    }
+}
+
+pub trait TryDedupStreamExt: Stream + TryStream + Sized {
+    /// Similar to [`dedup`](`DedupStreamExt::dedup`) but for `TryStream`.
+    ///
+    /// Only the `Ok` items are compared; the errors are always forwarded and do not reset the last emitted item.
+    fn try_dedup(self) -> TryDedup<Self, Self::Ok>
+    where
+        Self::Ok: PartialEq + Clone,
+    {
+        TryDedup::new(self)
+    }
+
+    /// Similar to [`dedup_by_key`](`DedupStreamExt::dedup_by_key`) but for `TryStream`.
+    ///
+    /// Only the `Ok` items are keyed; the errors are always forwarded and do not reset the last emitted key.
+    fn try_dedup_by_key<K, F>(self, key_fn: F) -> TryDedupByKey<Self, F, K>
+    where
+        F: FnMut(&Self::Ok) -> K,
+        K: PartialEq + Clone,
+    {
+        TryDedupByKey::new(self, key_fn)
+    }
+}
+
+/// Stream for [`dedup`](`DedupStreamExt::dedup`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct Dedup<Stream, Item> {
+    #[pin]
+    inner: Stream,
+
+    last: Option<Item>,
+}
+
+/// Stream for [`try_dedup`](`TryDedupStreamExt::try_dedup`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct TryDedup<Stream, Ok> {
+    #[pin]
+    inner: Stream,
+
+    last: Option<Ok>,
+}
+
+/// Stream for [`dedup_by_key`](`DedupStreamExt::dedup_by_key`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct DedupByKey<Stream, F, K> {
+    #[pin]
+    inner: Stream,
+    key_fn: F,
+
+    last: Option<K>,
+}
+
+/// Stream for [`try_dedup_by_key`](`TryDedupStreamExt::try_dedup_by_key`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct TryDedupByKey<Stream, F, K> {
+    #[pin]
+    inner: Stream,
+    key_fn: F,
+
+    last: Option<K>,
+}
+
+impl<S, I> Dedup<S, I> {
+    pub fn new(inner: S) -> Self {
+        Self { inner, last: None }
+    }
+}
+
+impl<S, T> TryDedup<S, T> {
+    pub fn new(inner: S) -> Self {
+        Self { inner, last: None }
+    }
+}
+
+impl<S, F, K> DedupByKey<S, F, K> {
+    pub fn new(inner: S, key_fn: F) -> Self {
+        Self {
+            inner,
+            key_fn,
+            last: None,
+        }
+    }
+}
+
+impl<S, F, K> TryDedupByKey<S, F, K> {
+    pub fn new(inner: S, key_fn: F) -> Self {
+        Self {
+            inner,
+            key_fn,
+            last: None,
+        }
+    }
+}
+
+impl<S> Stream for Dedup<S, S::Item>
+where
+    S: Stream,
+    S::Item: PartialEq + Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            let Some(item) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                break None;
+            };
+            if this.last.as_ref() != Some(&item) {
+                *this.last = Some(item.clone());
+                break Some(item);
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        (lower.min(1), upper)
+    }
+}
+
+impl<S> Stream for TryDedup<S, S::Ok>
+where
+    S: Stream + TryStream,
+    S::Ok: PartialEq + Clone,
+{
+    type Item = Result<S::Ok, S::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            match ready!(this.inner.as_mut().try_poll_next(cx)) {
+                None => break None,
+                Some(Err(error)) => break Some(Err(error)),
+                Some(Ok(item)) if this.last.as_ref() == Some(&item) => continue,
+                Some(Ok(item)) => {
+                    *this.last = Some(item.clone());
+                    break Some(Ok(item));
+                }
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        (lower.min(1), upper)
+    }
+}
+
+impl<S, F, K> Stream for DedupByKey<S, F, K>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> K,
+    K: PartialEq + Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            let Some(item) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                break None;
+            };
+            let key = (this.key_fn)(&item);
+            if this.last.as_ref() != Some(&key) {
+                *this.last = Some(key);
+                break Some(item);
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        (lower.min(1), upper)
+    }
+}
+
+impl<S, F, K> Stream for TryDedupByKey<S, F, K>
+where
+    S: Stream + TryStream,
+    F: FnMut(&S::Ok) -> K,
+    K: PartialEq + Clone,
+{
+    type Item = Result<S::Ok, S::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            match ready!(this.inner.as_mut().try_poll_next(cx)) {
+                None => break None,
+                Some(Err(error)) => break Some(Err(error)),
+                Some(Ok(item)) => {
+                    let key = (this.key_fn)(&item);
+                    if this.last.as_ref() != Some(&key) {
+                        *this.last = Some(key);
+                        break Some(Ok(item));
+                    }
+                }
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        (lower.min(1), upper)
+    }
+}
+
+impl<S> DedupStreamExt for S where S: Stream + Sized {}
+
+impl<S> TryDedupStreamExt for S where S: Stream + TryStream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<()>()
+            .dedup()
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn runs_of_equal_items_are_collapsed() {
+        assert_eq!(
+            stream::iter([1, 1, 1, 2, 2, 3, 1, 1])
+                .dedup()
+                .collect::<Vec<_>>()
+                .await,
+            vec![1, 2, 3, 1]
+        );
+    }
+
+    #[tokio::test]
+    async fn alternating_items_pass_through() {
+        assert_eq!(
+            stream::iter([1, 2, 1, 2]).dedup().collect::<Vec<_>>().await,
+            vec![1, 2, 1, 2]
+        );
+    }
+
+    #[tokio::test]
+    async fn empty_try_stream() {
+        assert!(stream::empty::<Result<(), ()>>()
+            .try_dedup()
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn errors_are_always_forwarded() {
+        assert_eq!(
+            stream::iter([Ok(1), Ok(1), Err("e"), Err("e"), Ok(1), Ok(2), Ok(2)])
+                .try_dedup()
+                .collect::<Vec<_>>()
+                .await,
+            vec![Ok(1), Err("e"), Err("e"), Ok(2)]
+        );
+    }
+
+    #[tokio::test]
+    async fn alternating_items_pass_through_try_stream() {
+        assert_eq!(
+            stream::iter([Ok::<_, ()>(1), Ok(2), Ok(1)])
+                .try_dedup()
+                .collect::<Vec<_>>()
+                .await,
+            vec![Ok(1), Ok(2), Ok(1)]
+        );
+    }
+
+    #[tokio::test]
+    async fn items_with_repeating_keys_are_dropped() {
+        assert_eq!(
+            stream::iter([(1, 'a'), (1, 'b'), (2, 'c'), (2, 'd'), (1, 'e')])
+                .dedup_by_key(|(seq, _)| *seq)
+                .collect::<Vec<_>>()
+                .await,
+            vec![(1, 'a'), (2, 'c'), (1, 'e')]
+        );
+    }
+
+    #[tokio::test]
+    async fn equal_items_with_distinct_keys_pass_through() {
+        assert_eq!(
+            stream::iter(["x", "x", "x"])
+                .enumerate()
+                .dedup_by_key(|(seq, _)| *seq)
+                .map(|(_, value)| value)
+                .collect::<Vec<_>>()
+                .await,
+            vec!["x", "x", "x"]
+        );
+    }
+
+    #[tokio::test]
+    async fn only_ok_items_are_keyed() {
+        assert_eq!(
+            stream::iter([
+                Ok((1, 'a')),
+                Ok((1, 'b')),
+                Err("e"),
+                Ok((1, 'c')),
+                Ok((2, 'd'))
+            ])
+            .try_dedup_by_key(|(seq, _)| *seq)
+            .collect::<Vec<_>>()
+            .await,
+            vec![Ok((1, 'a')), Err("e"), Ok((2, 'd'))]
+        );
+    }
+}
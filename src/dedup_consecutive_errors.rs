@@ -0,0 +1,125 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{Stream, TryStream};
+
+pub trait DedupConsecutiveErrorsStreamExt
+where
+    Self: Stream + TryStream + Sized,
+    Self::Error: PartialEq + Clone,
+{
+    /// Pass a lenient fallible stream through, dropping each error equal to the error right before it.
+    ///
+    /// Only the first error of a run of identical errors is emitted; an `Ok` item ends the run.
+    fn dedup_consecutive_errors(self) -> DedupConsecutiveErrors<Self, Self::Error> {
+        DedupConsecutiveErrors::new(self)
+    }
+}
+
+/// Stream for [`dedup_consecutive_errors`](`DedupConsecutiveErrorsStreamExt::dedup_consecutive_errors`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct DedupConsecutiveErrors<Stream, Error> {
+    #[pin]
+    inner: Stream,
+
+    last_error: Option<Error>,
+}
+
+impl<S> DedupConsecutiveErrors<S, S::Error>
+where
+    S: TryStream,
+{
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            last_error: None,
+        }
+    }
+}
+
+impl<S> Stream for DedupConsecutiveErrors<S, S::Error>
+where
+    S: Stream + TryStream,
+    S::Error: PartialEq + Clone,
+{
+    type Item = Result<S::Ok, S::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            match ready!(this.inner.as_mut().try_poll_next(cx)) {
+                None => break None,
+                Some(Ok(item)) => {
+                    *this.last_error = None;
+                    break Some(Ok(item));
+                }
+                Some(Err(error)) if this.last_error.as_ref() == Some(&error) => continue,
+                Some(Err(error)) => {
+                    *this.last_error = Some(error.clone());
+                    break Some(Err(error));
+                }
+            }
+        })
+    }
+}
+
+impl<S> DedupConsecutiveErrorsStreamExt for S
+where
+    S: Stream + TryStream + Sized,
+    S::Error: PartialEq + Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn oks_pass_through() {
+        assert_eq!(
+            stream::iter([Ok::<_, ()>(1), Ok(2)])
+                .dedup_consecutive_errors()
+                .collect::<Vec<_>>()
+                .await,
+            vec![Ok(1), Ok(2)]
+        );
+    }
+
+    #[tokio::test]
+    async fn only_the_first_error_of_a_run_is_emitted() {
+        assert_eq!(
+            stream::iter([
+                Ok(1),
+                Err("timeout"),
+                Err("timeout"),
+                Err("timeout"),
+                Err("refused"),
+                Err("refused"),
+                Err("timeout"),
+            ])
+            .dedup_consecutive_errors()
+            .collect::<Vec<_>>()
+            .await,
+            vec![Ok(1), Err("timeout"), Err("refused"), Err("timeout")]
+        );
+    }
+
+    #[tokio::test]
+    async fn an_ok_ends_the_run() {
+        assert_eq!(
+            stream::iter([Err("timeout"), Err("timeout"), Ok(1), Err("timeout")])
+                .dedup_consecutive_errors()
+                .collect::<Vec<_>>()
+                .await,
+            vec![Err("timeout"), Ok(1), Err("timeout")]
+        );
+    }
+}
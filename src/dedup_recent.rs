@@ -0,0 +1,132 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    hash::Hash,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait DedupRecentStreamExt: Stream + Sized {
+    /// Drop each item whose key, as computed with `key`, is among the keys of the last `n` emitted items.
+    ///
+    /// Once a key falls out of that window, an item with that key is emitted again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    fn dedup_recent<F, K>(self, n: usize, key: F) -> DedupRecent<Self, F, K>
+    where
+        F: FnMut(&Self::Item) -> K,
+        K: Eq + Hash + Clone,
+    {
+        DedupRecent::new(self, n, key)
+    }
+}
+
+/// Stream for [`dedup_recent`](`DedupRecentStreamExt::dedup_recent`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct DedupRecent<Stream, F, K> {
+    #[pin]
+    inner: Stream,
+    n: usize,
+    key: F,
+
+    recent: VecDeque<K>,
+    seen: HashSet<K>,
+}
+
+impl<S, F, K> DedupRecent<S, F, K> {
+    pub fn new(inner: S, n: usize, key: F) -> Self {
+        assert!(n > 0, "the window must not be empty");
+        Self {
+            inner,
+            n,
+            key,
+            recent: VecDeque::with_capacity(n),
+            seen: HashSet::with_capacity(n),
+        }
+    }
+}
+
+impl<S, F, K> Stream for DedupRecent<S, F, K>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> K,
+    K: Eq + Hash + Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            let Some(item) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                break None;
+            };
+
+            let key = (this.key)(&item);
+            if this.seen.contains(&key) {
+                continue;
+            }
+            if this.recent.len() == *this.n {
+                let forgotten = this.recent.pop_front().expect("the window is full");
+                this.seen.remove(&forgotten);
+            }
+            this.recent.push_back(key.clone());
+            this.seen.insert(key);
+            break Some(item);
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        (lower.min(1), upper)
+    }
+}
+
+impl<S> DedupRecentStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn recent_keys_are_dropped() {
+        assert_eq!(
+            stream::iter([1, 2, 1, 2, 3, 3, 2])
+                .dedup_recent(3, |n| *n)
+                .collect::<Vec<_>>()
+                .await,
+            vec![1, 2, 3]
+        );
+    }
+
+    #[tokio::test]
+    async fn keys_reappear_once_forgotten() {
+        assert_eq!(
+            stream::iter(["a", "b", "a", "c", "a", "b", "c"])
+                .dedup_recent(2, |s| *s)
+                .collect::<Vec<_>>()
+                .await,
+            vec!["a", "b", "c", "a", "b", "c"]
+        );
+    }
+
+    #[tokio::test]
+    async fn items_are_compared_by_key() {
+        assert_eq!(
+            stream::iter([(1, 'a'), (1, 'b'), (2, 'c'), (3, 'd'), (1, 'e')])
+                .dedup_recent(2, |(k, _)| *k)
+                .map(|(_, v)| v)
+                .collect::<Vec<_>>()
+                .await,
+            vec!['a', 'c', 'd', 'e']
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use futures::Stream;
+
+use crate::sync_utils::{lock, wake_on_drop};
+
+pub trait DemuxStreamExt: Stream + Sized {
+    /// Fan the items out to per-key substreams, created with [`Demux::stream_for`].
+    ///
+    /// All the substreams share the upstream: whichever one is polled pulls the items and buffers those
+    /// of the other keys, including the keys no substream has been created for yet. The buffers are unbounded,
+    /// so the items of a key that is never consumed are kept for as long as the [`Demux`] or any of its
+    /// substreams is alive. Once a substream is dropped, the items of its key are discarded
+    /// (until another substream for that key is created).
+    fn demux<K, F>(self, key: F) -> Demux<Self, K, F>
+    where
+        F: FnMut(&Self::Item) -> K,
+        K: Eq + Hash + Clone,
+    {
+        Demux::new(self, key)
+    }
+}
+
+/// The source of the substreams of [`demux`](`DemuxStreamExt::demux`).
+#[derive(Debug)]
+pub struct Demux<S: Stream, K, F> {
+    shared: SharedRef<S, K, F, S::Item>,
+}
+
+/// The substream of a single key of [`Demux`].
+#[derive(Debug)]
+pub struct DemuxStream<S: Stream, K: Eq + Hash, F> {
+    shared: SharedRef<S, K, F, S::Item>,
+    key: K,
+}
+
+type SharedRef<S, K, F, I> = Arc<Mutex<Shared<S, K, F, I>>>;
+
+#[derive(Debug)]
+struct Shared<S, K, F, I> {
+    inner: Pin<Box<S>>,
+    key: F,
+
+    ended: bool,
+    buffers: HashMap<K, VecDeque<I>>,
+    wakers: HashMap<K, Waker>,
+    dropped: HashSet<K>,
+}
+
+impl<S, K, F> Demux<S, K, F>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> K,
+    K: Eq + Hash + Clone,
+{
+    pub fn new(inner: S, key: F) -> Self {
+        Self {
+            shared: Arc::new(Mutex::new(Shared {
+                inner: Box::pin(inner),
+                key,
+                ended: false,
+                buffers: HashMap::new(),
+                wakers: HashMap::new(),
+                dropped: HashSet::new(),
+            })),
+        }
+    }
+
+    /// A stream of the items with the given key.
+    ///
+    /// It is meant to be the only live substream of `key`; several ones share the items of the key between them.
+    pub fn stream_for(&mut self, key: K) -> DemuxStream<S, K, F> {
+        let mut shared = lock(&self.shared);
+        shared.dropped.remove(&key);
+        shared.buffers.entry(key.clone()).or_default();
+        drop(shared);
+
+        DemuxStream {
+            shared: self.shared.clone(),
+            key,
+        }
+    }
+}
+
+impl<S, K, F> Stream for DemuxStream<S, K, F>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> K,
+    K: Eq + Hash + Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut shared = lock(&self.shared);
+        let shared = &mut *shared;
+
+        loop {
+            if let Some(item) = shared
+                .buffers
+                .get_mut(&self.key)
+                .and_then(VecDeque::pop_front)
+            {
+                return Poll::Ready(Some(item));
+            }
+            if shared.ended {
+                return Poll::Ready(None);
+            }
+
+            match shared.inner.as_mut().poll_next(cx) {
+                Poll::Pending => {
+                    shared.wakers.insert(self.key.clone(), cx.waker().clone());
+                    return Poll::Pending;
+                }
+                Poll::Ready(None) => {
+                    shared.ended = true;
+                    shared.wakers.drain().for_each(|(_, waker)| waker.wake());
+                    return Poll::Ready(None);
+                }
+                Poll::Ready(Some(item)) => {
+                    let key = (shared.key)(&item);
+                    if key == self.key {
+                        return Poll::Ready(Some(item));
+                    }
+                    if shared.dropped.contains(&key) {
+                        continue;
+                    }
+                    if let Some(waker) = shared.wakers.remove(&key) {
+                        waker.wake();
+                    }
+                    shared.buffers.entry(key).or_default().push_back(item);
+                }
+            }
+        }
+    }
+}
+
+impl<S, K, F> Drop for DemuxStream<S, K, F>
+where
+    S: Stream,
+    K: Eq + Hash,
+{
+    fn drop(&mut self) {
+        let mut shared = lock(&self.shared);
+        shared.wakers.remove(&self.key);
+        if let Some((key, _)) = shared.buffers.remove_entry(&self.key) {
+            shared.dropped.insert(key);
+        }
+        wake_on_drop(shared.wakers.drain().map(|(_, waker)| waker));
+    }
+}
+
+impl<S> DemuxStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{channel::mpsc, poll, stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn items_are_routed_by_key() {
+        let mut demux =
+            stream::iter([("a", 1), ("b", 2), ("a", 3), ("b", 4), ("a", 5)]).demux(|(key, _)| *key);
+        let a = demux.stream_for("a");
+        let b = demux.stream_for("b");
+
+        assert_eq!(a.map(|(_, x)| x).collect::<Vec<_>>().await, vec![1, 3, 5]);
+        assert_eq!(b.map(|(_, x)| x).collect::<Vec<_>>().await, vec![2, 4]);
+    }
+
+    #[tokio::test]
+    async fn items_of_a_key_are_buffered_before_it_is_subscribed() {
+        let mut demux = stream::iter(0..6).demux(|x| x % 2 == 0);
+        let odd = demux.stream_for(false);
+        assert_eq!(odd.collect::<Vec<_>>().await, vec![1, 3, 5]);
+
+        let even = demux.stream_for(true);
+        assert_eq!(even.collect::<Vec<_>>().await, vec![0, 2, 4]);
+    }
+
+    #[tokio::test]
+    async fn substreams_polled_in_turns() {
+        let (tx, rx) = mpsc::unbounded();
+        let mut demux = rx.demux(|x: &u8| *x / 10);
+        let mut ones = demux.stream_for(1);
+        let mut twos = demux.stream_for(2);
+
+        assert_eq!(poll!(ones.next()), Poll::Pending);
+        assert_eq!(poll!(twos.next()), Poll::Pending);
+
+        tx.unbounded_send(21).unwrap();
+        tx.unbounded_send(11).unwrap();
+        assert_eq!(poll!(ones.next()), Poll::Ready(Some(11)));
+        assert_eq!(poll!(twos.next()), Poll::Ready(Some(21)));
+
+        drop(tx);
+        assert_eq!(poll!(twos.next()), Poll::Ready(None));
+        assert_eq!(poll!(ones.next()), Poll::Ready(None));
+    }
+
+    #[tokio::test]
+    async fn items_of_a_dropped_substream_are_discarded() {
+        let mut demux = stream::iter(0..6).demux(|x| x % 2 == 0);
+        drop(demux.stream_for(true));
+
+        let odd = demux.stream_for(false);
+        assert_eq!(odd.collect::<Vec<_>>().await, vec![1, 3, 5]);
+
+        let even = demux.stream_for(true);
+        assert!(even.collect::<Vec<_>>().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn dropping_the_last_poller_wakes_the_other_substreams() {
+        use std::sync::atomic::Ordering;
+
+        use crate::test_utils::flag_waker;
+
+        let (tx, rx) = mpsc::unbounded();
+        let mut demux = rx.demux(|x: &u8| *x / 10);
+        let mut ones = Box::pin(demux.stream_for(1));
+        let mut twos = demux.stream_for(2);
+
+        let (waker, woken) = flag_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert_eq!(ones.as_mut().poll_next(&mut cx), Poll::Pending);
+        assert_eq!(poll!(twos.next()), Poll::Pending);
+
+        drop(twos);
+        tx.unbounded_send(11).unwrap();
+        assert!(woken.load(Ordering::Relaxed));
+        assert_eq!(ones.as_mut().poll_next(&mut cx), Poll::Ready(Some(11)));
+    }
+}
@@ -0,0 +1,122 @@
+use std::{
+    ops::Sub,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait DerivativesStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: Sub<Output = Self::Item> + Clone,
+{
+    /// Emit `(value, first_diff, second_diff)`: the value, its delta from the previous value, and the delta of those deltas.
+    ///
+    /// Both differences need history, so the first two items are consumed without emitting anything.
+    fn derivatives(self) -> Derivatives<Self, Self::Item> {
+        Derivatives::new(self)
+    }
+}
+
+/// Stream for [`derivatives`](`DerivativesStreamExt::derivatives`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct Derivatives<Stream, Item> {
+    #[pin]
+    inner: Stream,
+
+    previous: Option<Item>,
+    previous_diff: Option<Item>,
+}
+
+impl<S> Derivatives<S, S::Item>
+where
+    S: Stream,
+    S::Item: Sub<Output = S::Item> + Clone,
+{
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            previous: None,
+            previous_diff: None,
+        }
+    }
+}
+
+impl<S> Stream for Derivatives<S, S::Item>
+where
+    S: Stream,
+    S::Item: Sub<Output = S::Item> + Clone,
+{
+    type Item = (S::Item, S::Item, S::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            let Some(x) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                break None;
+            };
+
+            let Some(previous) = this.previous.replace(x.clone()) else {
+                continue;
+            };
+            let diff = x.clone() - previous;
+
+            let Some(previous_diff) = this.previous_diff.replace(diff.clone()) else {
+                continue;
+            };
+            let second_diff = diff.clone() - previous_diff;
+
+            break Some((x, diff, second_diff));
+        })
+    }
+}
+
+impl<S> DerivativesStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Sub<Output = S::Item> + Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn nothing_is_emitted_without_enough_history() {
+        assert!(stream::iter([1, 2])
+            .derivatives()
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn squares_have_a_constant_second_derivative() {
+        assert_eq!(
+            stream::iter([0, 1, 4, 9])
+                .derivatives()
+                .collect::<Vec<_>>()
+                .await,
+            vec![(4, 3, 2), (9, 5, 2)]
+        );
+    }
+
+    #[tokio::test]
+    async fn deceleration_is_negative() {
+        assert_eq!(
+            stream::iter([0.0, 10.0, 15.0, 17.5])
+                .derivatives()
+                .collect::<Vec<_>>()
+                .await,
+            vec![(15.0, 5.0, -5.0), (17.5, 2.5, -2.5)]
+        );
+    }
+}
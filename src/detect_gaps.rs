@@ -0,0 +1,140 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait DetectGapsStreamExt: Stream + Sized {
+    /// Emit a [`SeqEvent::Gap`] before an item whose sequence number jumps by more than one from the previous item.
+    ///
+    /// Regressions and repeats are not reported as gaps.
+    fn detect_gaps<F>(self, seq_of: F) -> DetectGaps<Self, Self::Item, F>
+    where
+        F: FnMut(&Self::Item) -> u64,
+    {
+        DetectGaps::new(self, seq_of)
+    }
+}
+
+/// An event produced by [`detect_gaps`](`DetectGapsStreamExt::detect_gaps`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeqEvent<T> {
+    Item(T),
+    /// The sequence numbers between `from` and `to` (both exclusive) are missing.
+    Gap {
+        from: u64,
+        to: u64,
+    },
+}
+
+/// Stream for [`detect_gaps`](`DetectGapsStreamExt::detect_gaps`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct DetectGaps<Stream, Item, F> {
+    #[pin]
+    inner: Stream,
+    seq_of: F,
+
+    last_seq: Option<u64>,
+    held: Option<Item>,
+}
+
+impl<S, F> DetectGaps<S, S::Item, F>
+where
+    S: Stream,
+{
+    pub fn new(inner: S, seq_of: F) -> Self {
+        Self {
+            inner,
+            seq_of,
+            last_seq: None,
+            held: None,
+        }
+    }
+}
+
+impl<S, F> Stream for DetectGaps<S, S::Item, F>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> u64,
+{
+    type Item = SeqEvent<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        if let Some(item) = this.held.take() {
+            return Poll::Ready(Some(SeqEvent::Item(item)));
+        }
+
+        let Some(item) = ready!(this.inner.poll_next(cx)) else {
+            return Poll::Ready(None);
+        };
+        let seq = (this.seq_of)(&item);
+
+        match this.last_seq.replace(seq) {
+            Some(last) if seq > last.saturating_add(1) => {
+                *this.held = Some(item);
+                Poll::Ready(Some(SeqEvent::Gap {
+                    from: last,
+                    to: seq,
+                }))
+            }
+            _ => Poll::Ready(Some(SeqEvent::Item(item))),
+        }
+    }
+}
+
+impl<S> DetectGapsStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<u64>()
+            .detect_gaps(|seq| *seq)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn gap_is_reported_before_the_item() {
+        assert_eq!(
+            stream::iter([0, 1, 4, 5])
+                .detect_gaps(|seq| *seq)
+                .collect::<Vec<_>>()
+                .await,
+            vec![
+                SeqEvent::Item(0),
+                SeqEvent::Item(1),
+                SeqEvent::Gap { from: 1, to: 4 },
+                SeqEvent::Item(4),
+                SeqEvent::Item(5),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn regressions_are_not_gaps() {
+        assert_eq!(
+            stream::iter([(3, 'a'), (3, 'b'), (1, 'c'), (2, 'd')])
+                .detect_gaps(|(seq, _)| *seq)
+                .collect::<Vec<_>>()
+                .await,
+            vec![
+                SeqEvent::Item((3, 'a')),
+                SeqEvent::Item((3, 'b')),
+                SeqEvent::Item((1, 'c')),
+                SeqEvent::Item((2, 'd')),
+            ]
+        );
+    }
+}
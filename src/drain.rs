@@ -0,0 +1,3 @@
+/// The most items an adapter drains from a stream within one poll; once it is spent, the adapter wakes itself
+/// and lets the executor run others before draining further, so that an always-ready stream cannot hang it.
+pub(crate) const BUDGET: usize = 32;
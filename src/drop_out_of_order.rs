@@ -0,0 +1,95 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait DropOutOfOrderStreamExt: Stream + Sized {
+    /// Emit only the items whose sequence number is greater than that of every item emitted before.
+    ///
+    /// Regressions and duplicates are silently dropped.
+    fn drop_out_of_order<F>(self, seq_of: F) -> DropOutOfOrder<Self, F>
+    where
+        F: FnMut(&Self::Item) -> u64,
+    {
+        DropOutOfOrder::new(self, seq_of)
+    }
+}
+
+/// Stream for [`drop_out_of_order`](`DropOutOfOrderStreamExt::drop_out_of_order`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct DropOutOfOrder<Stream, F> {
+    #[pin]
+    inner: Stream,
+    seq_of: F,
+
+    last_seq: Option<u64>,
+}
+
+impl<S, F> DropOutOfOrder<S, F> {
+    pub fn new(inner: S, seq_of: F) -> Self {
+        Self {
+            inner,
+            seq_of,
+            last_seq: None,
+        }
+    }
+}
+
+impl<S, F> Stream for DropOutOfOrder<S, F>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> u64,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            let Some(item) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                break None;
+            };
+            let seq = (this.seq_of)(&item);
+            if this.last_seq.is_none_or(|last| seq > last) {
+                *this.last_seq = Some(seq);
+                break Some(item);
+            }
+        })
+    }
+}
+
+impl<S> DropOutOfOrderStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn regressions_and_duplicates_are_dropped() {
+        assert_eq!(
+            stream::iter([(0, 'a'), (2, 'b'), (1, 'c'), (3, 'd'), (3, 'e'), (4, 'f')])
+                .drop_out_of_order(|(seq, _)| *seq)
+                .collect::<Vec<_>>()
+                .await,
+            vec![(0, 'a'), (2, 'b'), (3, 'd'), (4, 'f')]
+        );
+    }
+
+    #[tokio::test]
+    async fn sequence_zero_is_accepted_first() {
+        assert_eq!(
+            stream::iter([0u64, 0, 1])
+                .drop_out_of_order(|seq| *seq)
+                .collect::<Vec<_>>()
+                .await,
+            vec![0, 1]
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use std::{
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait EnsureAlternatingStreamExt: Stream + Sized {
+    /// Check that the items strictly alternate between the two categories reported by `category`.
+    ///
+    /// The first item establishes the starting category. Two consecutive items of the same category yield
+    /// [`AlternationViolation`], after which the stream ends.
+    fn ensure_alternating<F>(self, category: F) -> EnsureAlternating<Self, F>
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        EnsureAlternating::new(self, category)
+    }
+}
+
+/// Two consecutive items of the same category met by [`EnsureAlternating`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlternationViolation {
+    /// Zero-based index of the offending item.
+    pub index: usize,
+    /// The category repeated by the offending item.
+    pub category: bool,
+}
+
+impl fmt::Display for AlternationViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "item #{} repeats the category {} of the previous item",
+            self.index, self.category
+        )
+    }
+}
+
+impl std::error::Error for AlternationViolation {}
+
+/// Stream for [`ensure_alternating`](`EnsureAlternatingStreamExt::ensure_alternating`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct EnsureAlternating<Stream, F> {
+    #[pin]
+    inner: Stream,
+    category: F,
+
+    index: usize,
+    previous: Option<bool>,
+    terminated: bool,
+}
+
+impl<S, F> EnsureAlternating<S, F> {
+    pub fn new(inner: S, category: F) -> Self {
+        Self {
+            inner,
+            category,
+            index: 0,
+            previous: None,
+            terminated: false,
+        }
+    }
+}
+
+impl<S, F> Stream for EnsureAlternating<S, F>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> bool,
+{
+    type Item = Result<S::Item, AlternationViolation>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        if *this.terminated {
+            return Poll::Ready(None);
+        }
+
+        let Some(item) = ready!(this.inner.poll_next(cx)) else {
+            *this.terminated = true;
+            return Poll::Ready(None);
+        };
+
+        let index = *this.index;
+        *this.index += 1;
+
+        let category = (this.category)(&item);
+        if this.previous.replace(category) == Some(category) {
+            *this.terminated = true;
+            return Poll::Ready(Some(Err(AlternationViolation { index, category })));
+        }
+
+        Poll::Ready(Some(Ok(item)))
+    }
+}
+
+impl<S> EnsureAlternatingStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Msg {
+        Request(u32),
+        Response(u32),
+    }
+
+    fn is_request(msg: &Msg) -> bool {
+        matches!(msg, Msg::Request(_))
+    }
+
+    #[tokio::test]
+    async fn valid_alternation_passes_through() {
+        use Msg::*;
+
+        assert_eq!(
+            stream::iter([Request(1), Response(1), Request(2), Response(2)])
+                .ensure_alternating(is_request)
+                .collect::<Vec<_>>()
+                .await,
+            vec![
+                Ok(Request(1)),
+                Ok(Response(1)),
+                Ok(Request(2)),
+                Ok(Response(2))
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn the_first_item_sets_the_starting_category() {
+        use Msg::*;
+
+        assert_eq!(
+            stream::iter([Response(0), Request(1), Response(1)])
+                .ensure_alternating(is_request)
+                .collect::<Vec<_>>()
+                .await,
+            vec![Ok(Response(0)), Ok(Request(1)), Ok(Response(1))]
+        );
+    }
+
+    #[tokio::test]
+    async fn violation_ends_the_stream() {
+        use Msg::*;
+
+        assert_eq!(
+            stream::iter([Request(1), Response(1), Response(2), Request(3)])
+                .ensure_alternating(is_request)
+                .collect::<Vec<_>>()
+                .await,
+            vec![
+                Ok(Request(1)),
+                Ok(Response(1)),
+                Err(AlternationViolation {
+                    index: 2,
+                    category: false
+                })
+            ]
+        );
+    }
+}
@@ -1,9 +1,13 @@
 use std::{
     pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     task::{Context, Poll},
 };
 
-use futures::{Stream, TryStream};
+use futures::{stream::FusedStream, Stream, TryStream};
 
 pub trait ExpandStreamExt
 where
@@ -14,6 +18,49 @@ where
     fn expand(self) -> Expand<Self, Self::Item> {
         Expand::new(self)
     }
+
+    /// Yield a fresh item if the upstream has one ready, otherwise repeat the last one.
+    ///
+    /// This is the same adapter as [`expand`](`ExpandStreamExt::expand`), named after its demand-driven use:
+    /// the upstream is polled exactly once per poll, nothing is drained or produced ahead of the downstream.
+    fn latest_or_repeat(self) -> Expand<Self, Self::Item> {
+        Expand::new(self)
+    }
+
+    /// Similar to [`expand`](`ExpandStreamExt::expand`), reporting via [`FreshnessHandle::last_was_repeat`]
+    /// whether the most recently yielded item was a repeat.
+    fn expand_with_freshness(self) -> (ExpandFresh<Self, Self::Item>, FreshnessHandle) {
+        ExpandFresh::new(self)
+    }
+
+    /// Similar to [`expand`](`ExpandStreamExt::expand`), but repeats the last item at most `max_repeats` times.
+    ///
+    /// Once the limit is hit, the stream stays pending until the upstream produces a fresh item,
+    /// which resets the count.
+    fn expand_n(self, max_repeats: usize) -> ExpandN<Self, Self::Item> {
+        ExpandN::new(self, max_repeats)
+    }
+
+    /// Similar to [`expand`](`ExpandStreamExt::expand`), but repeats the last item only while `pred` holds for it.
+    ///
+    /// While the upstream is pending and `pred` rejects the last item, the stream is pending too.
+    /// Fresh items are always yielded.
+    fn expand_while<P>(self, pred: P) -> ExpandWhile<Self, P, Self::Item>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        ExpandWhile::new(self, pred)
+    }
+
+    /// Similar to [`expand`](`ExpandStreamExt::expand`), but yields to the executor between the repeats.
+    ///
+    /// After a repeated item, the next poll with the upstream still pending returns `Pending`
+    /// and wakes the task right away, so a downstream that keeps polling does not spin the CPU
+    /// with back-to-back repeats.
+    #[cfg(feature = "tokio")]
+    fn expand_yielding(self) -> ExpandYielding<Self, Self::Item> {
+        ExpandYielding::new(self)
+    }
 }
 
 pub trait TryExpandStreamExt
@@ -33,10 +80,65 @@ where
 pub struct Expand<Stream, Item> {
     #[pin]
     inner: Stream,
+    terminated: bool,
 
     last_poll: Poll<Option<Item>>,
 }
 
+/// Stream for [`expand_with_freshness`](`ExpandStreamExt::expand_with_freshness`) method.
+#[derive(Debug)]
+#[pin_project::pin_project]
+pub struct ExpandFresh<Stream, Item> {
+    #[pin]
+    inner: Expand<Stream, Item>,
+
+    repeat: Arc<AtomicBool>,
+}
+
+/// Read access to the freshness of the items yielded by an [`ExpandFresh`].
+#[derive(Debug, Clone)]
+pub struct FreshnessHandle {
+    repeat: Arc<AtomicBool>,
+}
+
+impl FreshnessHandle {
+    /// Whether the most recently yielded item was a repeat rather than a fresh upstream item.
+    pub fn last_was_repeat(&self) -> bool {
+        self.repeat.load(Ordering::Relaxed)
+    }
+}
+
+/// Stream for [`expand_n`](`ExpandStreamExt::expand_n`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct ExpandN<Stream, Item> {
+    #[pin]
+    inner: Expand<Stream, Item>,
+    max_repeats: usize,
+
+    repeats: usize,
+}
+
+/// Stream for [`expand_while`](`ExpandStreamExt::expand_while`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct ExpandWhile<Stream, P, Item> {
+    #[pin]
+    inner: Expand<Stream, Item>,
+    pred: P,
+}
+
+/// Stream for [`expand_yielding`](`ExpandStreamExt::expand_yielding`) method.
+#[cfg(feature = "tokio")]
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct ExpandYielding<Stream, Item> {
+    #[pin]
+    inner: Expand<Stream, Item>,
+
+    repeated: bool,
+}
+
 /// Stream for [`try_expand`](`TryExpandStreamExt::try_expand`) method.
 #[derive(Debug, Clone, Copy)]
 #[pin_project::pin_project]
@@ -56,9 +158,148 @@ where
     pub fn new(inner: S) -> Self {
         Self {
             inner,
+            terminated: false,
             last_poll: Poll::Pending,
         }
     }
+
+    /// Start as if `last` has already been produced by the upstream.
+    pub(crate) fn seeded(inner: S, last: S::Item) -> Self {
+        Self {
+            inner,
+            terminated: false,
+            last_poll: Poll::Ready(Some(last)),
+        }
+    }
+
+    /// Poll the upstream once, repeating the last item while it is pending only if `repeat` holds for that item.
+    ///
+    /// The yielded item is paired with whether it is a repeat. This is the step shared by the `expand_*` variants.
+    pub(crate) fn poll_next_with(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        mut repeat: impl FnMut(&S::Item) -> bool,
+    ) -> Poll<Option<(S::Item, bool)>> {
+        if self.terminated {
+            return Poll::Ready(None);
+        }
+
+        let mut this = self.project();
+        let this_poll = this.inner.as_mut().poll_next(cx);
+
+        match (this_poll, this.last_poll) {
+            (Poll::Pending, Poll::Ready(Some(last))) if repeat(last) => {
+                Poll::Ready(Some((last.clone(), true)))
+            }
+            (Poll::Pending, _) => Poll::Pending,
+            (Poll::Ready(None), last_poll) => {
+                *last_poll = Poll::Ready(None);
+                *this.terminated = true;
+                Poll::Ready(None)
+            }
+            (Poll::Ready(Some(newer)), last_poll) => {
+                *last_poll = Poll::Ready(Some(newer.clone()));
+                Poll::Ready(Some((newer, false)))
+            }
+        }
+    }
+}
+
+impl<S, I> Expand<S, I> {
+    /// Acquires a reference to the underlying stream.
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// Acquires a mutable reference to the underlying stream.
+    ///
+    /// Polling the underlying stream directly may confuse this adapter.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.inner
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream.
+    ///
+    /// Polling the underlying stream directly may confuse this adapter.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut S> {
+        self.project().inner
+    }
+
+    /// Consumes this adapter, returning the underlying stream.
+    ///
+    /// The buffered item, if any, is dropped.
+    ///
+    /// ```
+    /// use futures::{stream, Stream};
+    /// use streams::expand::ExpandStreamExt;
+    ///
+    /// let expanding = stream::iter([1, 2, 3]).expand();
+    /// let inner: stream::Iter<std::array::IntoIter<i32, 3>> = expanding.into_inner();
+    /// assert_eq!(inner.size_hint(), (3, Some(3)));
+    /// ```
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S> ExpandFresh<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    pub fn new(inner: S) -> (Self, FreshnessHandle) {
+        let repeat = Arc::new(AtomicBool::new(false));
+        let handle = FreshnessHandle {
+            repeat: repeat.clone(),
+        };
+        let fresh = Self {
+            inner: Expand::new(inner),
+            repeat,
+        };
+        (fresh, handle)
+    }
+}
+
+impl<S> ExpandN<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    pub fn new(inner: S, max_repeats: usize) -> Self {
+        Self {
+            inner: Expand::new(inner),
+            max_repeats,
+            repeats: 0,
+        }
+    }
+}
+
+impl<S, P> ExpandWhile<S, P, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+    P: FnMut(&S::Item) -> bool,
+{
+    pub fn new(inner: S, pred: P) -> Self {
+        Self {
+            inner: Expand::new(inner),
+            pred,
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<S> ExpandYielding<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner: Expand::new(inner),
+            repeated: false,
+        }
+    }
 }
 
 impl<S> TryExpand<S, S::Ok>
@@ -75,6 +316,43 @@ where
     }
 }
 
+impl<S, I> TryExpand<S, I> {
+    /// Acquires a reference to the underlying stream.
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// Acquires a mutable reference to the underlying stream.
+    ///
+    /// Polling the underlying stream directly may confuse this adapter.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.inner
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream.
+    ///
+    /// Polling the underlying stream directly may confuse this adapter.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut S> {
+        self.project().inner
+    }
+
+    /// Consumes this adapter, returning the underlying stream.
+    ///
+    /// The buffered item, if any, is dropped.
+    ///
+    /// ```
+    /// use futures::{stream, Stream};
+    /// use streams::expand::TryExpandStreamExt;
+    ///
+    /// let expanding = stream::iter([Ok::<_, ()>(1), Ok(2)]).try_expand();
+    /// let inner = expanding.into_inner();
+    /// assert_eq!(inner.size_hint(), (2, Some(2)));
+    /// ```
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
 impl<S> Stream for Expand<S, S::Item>
 where
     S: Stream,
@@ -83,20 +361,177 @@ where
     type Item = S::Item;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let mut this = self.project();
-        let this_poll = this.inner.as_mut().poll_next(cx);
+        self.poll_next_with(cx, |_| true)
+            .map(|item| item.map(|(item, _)| item))
+    }
 
-        match (this_poll, this.last_poll) {
-            (Poll::Pending, Poll::Pending) => Poll::Pending,
-            (Poll::Pending, Poll::Ready(last_ready)) => Poll::Ready(last_ready.clone()),
-            (Poll::Ready(newer), last_poll) => {
-                *last_poll = Poll::Ready(newer);
-                last_poll.clone()
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.terminated {
+            return (0, Some(0));
+        }
+        // The buffered item does not count towards the lower bound:
+        // the end of the upstream is forwarded right away, without repeating it.
+        (self.inner.size_hint().0, None)
+    }
+}
+
+impl<S> FusedStream for Expand<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<S> Stream for ExpandFresh<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        this.inner.poll_next_with(cx, |_| true).map(|item| {
+            item.map(|(item, repeat)| {
+                this.repeat.store(repeat, Ordering::Relaxed);
+                item
+            })
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S> FusedStream for ExpandFresh<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<S> Stream for ExpandN<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let can_repeat = *this.repeats < *this.max_repeats;
+        this.inner.poll_next_with(cx, |_| can_repeat).map(|item| {
+            item.map(|(item, repeat)| {
+                *this.repeats = if repeat { *this.repeats + 1 } else { 0 };
+                item
+            })
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.inner.terminated {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.inner.inner.size_hint();
+        let repeats_per_item = self.max_repeats.checked_add(1);
+        let upper = upper
+            .zip(repeats_per_item)
+            .and_then(|(upper, per_item)| upper.checked_mul(per_item))
+            .and_then(|upper| match self.inner.last_poll {
+                Poll::Ready(Some(_)) => upper.checked_add(self.max_repeats - self.repeats),
+                _ => Some(upper),
+            });
+        (lower, upper)
+    }
+}
+
+impl<S> FusedStream for ExpandN<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<S, P> Stream for ExpandWhile<S, P, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+    P: FnMut(&S::Item) -> bool,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        this.inner
+            .poll_next_with(cx, this.pred)
+            .map(|item| item.map(|(item, _)| item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S, P> FusedStream for ExpandWhile<S, P, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+    P: FnMut(&S::Item) -> bool,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<S> Stream for ExpandYielding<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let repeated = *this.repeated;
+        match this.inner.poll_next_with(cx, |_| !repeated) {
+            // Set only after a repeat, so there is a last item and it was declined above:
+            // yield to the executor before repeating it again.
+            Poll::Pending if repeated => {
+                *this.repeated = false;
+                cx.waker().wake_by_ref();
+                Poll::Pending
             }
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(item) => Poll::Ready(item.map(|(item, repeat)| {
+                *this.repeated = repeat;
+                item
+            })),
         }
     }
 }
 
+#[cfg(feature = "tokio")]
+impl<S> FusedStream for ExpandYielding<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
 impl<S> Stream for TryExpand<S, S::Ok>
 where
     S: Stream + TryStream,
@@ -148,7 +583,7 @@ where
 mod tests {
     use futures::{stream, StreamExt};
 
-    use crate::test_utils::ready_after_n_polls;
+    use crate::test_utils::{counting_polls, ready_after_n_polls};
 
     use super::*;
 
@@ -209,6 +644,224 @@ mod tests {
         );
     }
 
+    #[test]
+    fn the_upstream_is_not_polled_after_the_end() {
+        use std::sync::atomic::Ordering;
+
+        let (inner, polls) = counting_polls(stream::iter([1]));
+        let mut expanding = inner.expand();
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        assert!(!expanding.is_terminated());
+        assert_eq!(expanding.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+        assert_eq!(expanding.poll_next_unpin(&mut cx), Poll::Ready(None));
+        assert!(expanding.is_terminated());
+        assert_eq!(expanding.poll_next_unpin(&mut cx), Poll::Ready(None));
+        assert_eq!(expanding.poll_next_unpin(&mut cx), Poll::Ready(None));
+        assert_eq!(polls.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn size_hint_is_unbounded_until_terminated() {
+        let mut expanding = stream::iter([1, 2, 3]).expand();
+        assert_eq!(expanding.size_hint(), (3, None));
+
+        assert_eq!(expanding.next().await, Some(1));
+        assert_eq!(expanding.size_hint(), (2, None));
+
+        assert_eq!(expanding.next().await, Some(2));
+        assert_eq!(expanding.next().await, Some(3));
+        assert_eq!(expanding.size_hint(), (0, None));
+
+        assert_eq!(expanding.next().await, None);
+        assert_eq!(expanding.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn latest_or_repeat_polls_the_upstream_once_per_poll() {
+        use std::sync::atomic::Ordering;
+
+        use crate::latest_ready::LatestReadyStreamExt;
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        let (inner, polls) = counting_polls(stream::iter([1, 2, 3]).chain(stream::pending()));
+        let mut repeating = inner.latest_or_repeat();
+        for expected in [1, 2, 3, 3, 3] {
+            assert_eq!(
+                repeating.poll_next_unpin(&mut cx),
+                Poll::Ready(Some(expected))
+            );
+        }
+        assert_eq!(polls.load(Ordering::Relaxed), 5);
+
+        // Unlike a draining adapter, which polls the upstream until it runs dry.
+        let (inner, polls) = counting_polls(stream::iter([1, 2, 3]).chain(stream::pending()));
+        let mut draining = inner.latest_ready();
+        assert_eq!(draining.poll_next_unpin(&mut cx), Poll::Ready(Some(3)));
+        assert_eq!(polls.load(Ordering::Relaxed), 4);
+    }
+
+    #[tokio::test]
+    async fn freshness_flips_between_fresh_items_and_repeats() {
+        use futures::{channel::mpsc, poll};
+
+        let (tx, rx) = mpsc::unbounded();
+        let (mut expanding, freshness) = rx.expand_with_freshness();
+        assert!(!freshness.last_was_repeat());
+
+        assert_eq!(poll!(expanding.next()), Poll::Pending);
+        assert!(!freshness.last_was_repeat());
+
+        tx.unbounded_send(1).unwrap();
+        assert_eq!(poll!(expanding.next()), Poll::Ready(Some(1)));
+        assert!(!freshness.last_was_repeat());
+        assert_eq!(poll!(expanding.next()), Poll::Ready(Some(1)));
+        assert!(freshness.last_was_repeat());
+        assert_eq!(poll!(expanding.next()), Poll::Ready(Some(1)));
+        assert!(freshness.last_was_repeat());
+
+        tx.unbounded_send(2).unwrap();
+        assert_eq!(poll!(expanding.next()), Poll::Ready(Some(2)));
+        assert!(!freshness.last_was_repeat());
+    }
+
+    #[tokio::test]
+    async fn expand_n_repeats_at_most_n_times() {
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .chain(stream::once(ready_after_n_polls(4, 5)))
+                .expand_n(2)
+                .collect::<Vec<_>>()
+                .await,
+            vec![1, 2, 3, 3, 3, 4]
+        );
+    }
+
+    #[tokio::test]
+    async fn expand_n_fresh_item_resets_the_count() {
+        use futures::{channel::mpsc, poll};
+
+        let (tx, rx) = mpsc::unbounded();
+        let mut expanding = rx.expand_n(1);
+
+        assert_eq!(poll!(expanding.next()), Poll::Pending);
+        tx.unbounded_send(1).unwrap();
+        assert_eq!(poll!(expanding.next()), Poll::Ready(Some(1)));
+        assert_eq!(poll!(expanding.next()), Poll::Ready(Some(1)));
+        assert_eq!(poll!(expanding.next()), Poll::Pending);
+        assert_eq!(poll!(expanding.next()), Poll::Pending);
+
+        tx.unbounded_send(2).unwrap();
+        assert_eq!(poll!(expanding.next()), Poll::Ready(Some(2)));
+        assert_eq!(poll!(expanding.next()), Poll::Ready(Some(2)));
+        assert_eq!(poll!(expanding.next()), Poll::Pending);
+
+        drop(tx);
+        assert_eq!(poll!(expanding.next()), Poll::Ready(None));
+        assert!(expanding.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn expand_n_with_zero_repeats_never_repeats() {
+        assert_eq!(
+            stream::iter([1, 2])
+                .chain(stream::once(ready_after_n_polls(3, 3)))
+                .expand_n(0)
+                .collect::<Vec<_>>()
+                .await,
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn expand_n_size_hint_accounts_for_the_repeats() {
+        assert_eq!(
+            stream::iter([1, 2, 3]).expand_n(2).size_hint(),
+            (3, Some(9))
+        );
+        assert_eq!(
+            stream::repeat(1).expand_n(2).size_hint(),
+            (usize::MAX, None)
+        );
+    }
+
+    #[tokio::test]
+    async fn expand_while_repeats_only_while_the_predicate_holds() {
+        use futures::{channel::mpsc, poll};
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Status {
+            Connecting,
+            Disconnected,
+        }
+
+        let (tx, rx) = mpsc::unbounded();
+        let mut expanding = rx.expand_while(|status| *status == Status::Connecting);
+
+        tx.unbounded_send(Status::Connecting).unwrap();
+        assert_eq!(
+            poll!(expanding.next()),
+            Poll::Ready(Some(Status::Connecting))
+        );
+        assert_eq!(
+            poll!(expanding.next()),
+            Poll::Ready(Some(Status::Connecting))
+        );
+        assert_eq!(
+            poll!(expanding.next()),
+            Poll::Ready(Some(Status::Connecting))
+        );
+
+        tx.unbounded_send(Status::Disconnected).unwrap();
+        assert_eq!(
+            poll!(expanding.next()),
+            Poll::Ready(Some(Status::Disconnected))
+        );
+        assert_eq!(poll!(expanding.next()), Poll::Pending);
+        assert_eq!(poll!(expanding.next()), Poll::Pending);
+
+        tx.unbounded_send(Status::Connecting).unwrap();
+        assert_eq!(
+            poll!(expanding.next()),
+            Poll::Ready(Some(Status::Connecting))
+        );
+        assert_eq!(
+            poll!(expanding.next()),
+            Poll::Ready(Some(Status::Connecting))
+        );
+
+        drop(tx);
+        assert_eq!(poll!(expanding.next()), Poll::Ready(None));
+    }
+
+    #[tokio::test]
+    async fn expand_while_passes_fresh_items_through() {
+        assert_eq!(
+            stream::iter([1, 2, 3, 4])
+                .chain(stream::once(ready_after_n_polls(5, 2)))
+                .expand_while(|n| n % 2 == 0)
+                .collect::<Vec<_>>()
+                .await,
+            vec![1, 2, 3, 4, 4, 4, 5]
+        );
+    }
+
+    #[tokio::test]
+    async fn accessors_reach_the_underlying_stream() {
+        let mut expanding = stream::iter([1, 2, 3]).expand();
+        assert_eq!(expanding.get_ref().size_hint(), (3, Some(3)));
+        assert_eq!(expanding.get_mut().next().await, Some(1));
+        assert_eq!(Pin::new(&mut expanding).get_pin_mut().next().await, Some(2));
+        assert_eq!(expanding.next().await, Some(3));
+        assert_eq!(expanding.into_inner().next().await, None);
+
+        let mut try_expanding = stream::iter([Ok::<_, ()>(1), Ok(2)]).try_expand();
+        assert_eq!(try_expanding.get_mut().next().await, Some(Ok(1)));
+        assert_eq!(try_expanding.next().await, Some(Ok(2)));
+        assert_eq!(try_expanding.get_ref().size_hint(), (0, Some(0)));
+    }
+
     #[tokio::test]
     async fn try_stream_normal_termination() {
         assert_eq!(
@@ -230,4 +883,69 @@ mod tests {
             vec![Ok(1), Ok(2), Err(())]
         );
     }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn expand_yielding_yields_between_repeats() {
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+
+        use futures::task::{self, ArcWake};
+
+        #[derive(Default)]
+        struct CountingWaker(AtomicUsize);
+
+        impl ArcWake for CountingWaker {
+            fn wake_by_ref(arc_self: &Arc<Self>) {
+                arc_self.0.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let wakes = Arc::new(CountingWaker::default());
+        let waker = task::waker(wakes.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        let mut expanding = stream::iter([1]).chain(stream::pending()).expand_yielding();
+        let mut poll = || expanding.poll_next_unpin(&mut cx);
+
+        assert_eq!(poll(), Poll::Ready(Some(1)));
+        assert_eq!(poll(), Poll::Ready(Some(1)));
+        assert_eq!(poll(), Poll::Pending);
+        assert_eq!(wakes.0.load(Ordering::Relaxed), 1);
+        assert_eq!(poll(), Poll::Ready(Some(1)));
+        assert_eq!(poll(), Poll::Pending);
+        assert_eq!(wakes.0.load(Ordering::Relaxed), 2);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn expand_yielding_still_repeats_under_an_executor() {
+        assert_eq!(
+            stream::iter([1, 2])
+                .chain(stream::once(ready_after_n_polls(3, 4)))
+                .expand_yielding()
+                .collect::<Vec<_>>()
+                .await,
+            vec![1, 2, 2, 2, 3]
+        );
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn expand_yielding_does_not_poll_past_the_end() {
+        use std::sync::atomic::Ordering;
+
+        let (counted, polls) = counting_polls(stream::iter([1]));
+        let mut expanding = counted.expand_yielding();
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        assert_eq!(expanding.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+        assert_eq!(expanding.poll_next_unpin(&mut cx), Poll::Ready(None));
+        assert!(expanding.is_terminated());
+        assert_eq!(expanding.poll_next_unpin(&mut cx), Poll::Ready(None));
+        assert_eq!(expanding.poll_next_unpin(&mut cx), Poll::Ready(None));
+        assert_eq!(polls.load(Ordering::Relaxed), 2);
+    }
 }
@@ -0,0 +1,139 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait FilterWindowStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: Clone,
+{
+    /// Keep an item only if `f` accepts it given the last `n` upstream items (oldest first).
+    ///
+    /// The window holds every upstream item, including the rejected ones, and is shorter than `n` at the start.
+    fn filter_window<F>(self, n: usize, f: F) -> FilterWindow<Self, Self::Item, F>
+    where
+        F: FnMut(&[Self::Item], &Self::Item) -> bool,
+    {
+        FilterWindow::new(self, n, f)
+    }
+}
+
+/// Stream for [`filter_window`](`FilterWindowStreamExt::filter_window`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct FilterWindow<Stream, Item, F> {
+    #[pin]
+    inner: Stream,
+    n: usize,
+    f: F,
+
+    window: VecDeque<Item>,
+}
+
+impl<S, F> FilterWindow<S, S::Item, F>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    pub fn new(inner: S, n: usize, f: F) -> Self {
+        Self {
+            inner,
+            n,
+            f,
+            window: VecDeque::with_capacity(n),
+        }
+    }
+}
+
+impl<S, F> Stream for FilterWindow<S, S::Item, F>
+where
+    S: Stream,
+    S::Item: Clone,
+    F: FnMut(&[S::Item], &S::Item) -> bool,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            let Some(item) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                break None;
+            };
+            let keep = (this.f)(this.window.make_contiguous(), &item);
+
+            if *this.n > 0 {
+                if this.window.len() == *this.n {
+                    this.window.pop_front();
+                }
+                this.window.push_back(item.clone());
+            }
+
+            if keep {
+                break Some(item);
+            }
+        })
+    }
+}
+
+impl<S> FilterWindowStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<i32>()
+            .filter_window(3, |_, _| true)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn keeps_items_greater_than_the_whole_window() {
+        assert_eq!(
+            stream::iter([1, 3, 2, 5, 4, 4, 1, 6])
+                .filter_window(2, |window, item| window.iter().all(|prev| item > prev))
+                .collect::<Vec<_>>()
+                .await,
+            vec![1, 3, 5, 6]
+        );
+    }
+
+    #[tokio::test]
+    async fn window_holds_at_most_n_items() {
+        assert_eq!(
+            stream::iter(0..6)
+                .filter_window(3, |window, _| window.len() == 3)
+                .collect::<Vec<_>>()
+                .await,
+            vec![3, 4, 5]
+        );
+    }
+
+    #[tokio::test]
+    async fn zero_sized_window_is_always_empty() {
+        assert_eq!(
+            stream::iter(0..3)
+                .filter_window(0, |window, _| window.is_empty())
+                .collect::<Vec<_>>()
+                .await,
+            vec![0, 1, 2]
+        );
+    }
+}
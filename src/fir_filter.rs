@@ -0,0 +1,136 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait FirFilterStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: Into<f64>,
+{
+    /// Apply a finite impulse response filter: emit the dot product of `kernel` with the last `kernel.len()` values.
+    ///
+    /// `kernel[0]` weights the newest value, `kernel[1]` the one before it, and so on.
+    /// Nothing is emitted until the window is full. If `normalize` is set, the output is divided
+    /// by the sum of the kernel (unless that sum is zero).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `kernel` is empty.
+    fn fir_filter(self, kernel: Vec<f64>, normalize: bool) -> FirFilter<Self> {
+        FirFilter::new(self, kernel, normalize)
+    }
+}
+
+/// Stream for [`fir_filter`](`FirFilterStreamExt::fir_filter`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct FirFilter<Stream> {
+    #[pin]
+    inner: Stream,
+    kernel: Vec<f64>,
+    scale: f64,
+
+    window: VecDeque<f64>,
+}
+
+impl<S> FirFilter<S> {
+    pub fn new(inner: S, kernel: Vec<f64>, normalize: bool) -> Self {
+        assert!(!kernel.is_empty(), "the kernel must not be empty");
+
+        let sum = kernel.iter().sum::<f64>();
+        let scale = if normalize && sum != 0.0 { sum } else { 1.0 };
+        let window = VecDeque::with_capacity(kernel.len());
+
+        Self {
+            inner,
+            kernel,
+            scale,
+            window,
+        }
+    }
+}
+
+impl<S> Stream for FirFilter<S>
+where
+    S: Stream,
+    S::Item: Into<f64>,
+{
+    type Item = f64;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            let Some(x) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                break None;
+            };
+
+            if this.window.len() == this.kernel.len() {
+                this.window.pop_back();
+            }
+            this.window.push_front(x.into());
+            if this.window.len() < this.kernel.len() {
+                continue;
+            }
+
+            let dot = this
+                .window
+                .iter()
+                .zip(this.kernel.iter())
+                .map(|(x, k)| x * k)
+                .sum::<f64>();
+            break Some(dot / *this.scale);
+        })
+    }
+}
+
+impl<S> FirFilterStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Into<f64>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn nothing_is_emitted_until_the_window_is_full() {
+        assert!(stream::iter([1.0, 2.0])
+            .fir_filter(vec![1.0; 3], true)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn normalized_averaging_kernel() {
+        assert_eq!(
+            stream::iter([1u8, 2, 3, 4, 5, 6])
+                .fir_filter(vec![1.0; 3], true)
+                .collect::<Vec<_>>()
+                .await,
+            vec![2.0, 3.0, 4.0, 5.0]
+        );
+    }
+
+    #[tokio::test]
+    async fn the_first_coefficient_weights_the_newest_value() {
+        assert_eq!(
+            stream::iter([1.0, 4.0, 9.0, 16.0])
+                .fir_filter(vec![1.0, -1.0], false)
+                .collect::<Vec<_>>()
+                .await,
+            vec![3.0, 5.0, 7.0]
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{Stream, TryStream};
+
+pub trait FirstOkStreamExt: Stream + TryStream + Sized {
+    /// Skip the leading errors, yield the first `Ok` item, and end.
+    ///
+    /// If the upstream ends without an `Ok` item, the last error is yielded instead (nothing, if there were no items).
+    fn first_ok(self) -> FirstOk<Self, Self::Error> {
+        FirstOk::new(self)
+    }
+
+    /// Similar to [`first_ok`](`FirstOkStreamExt::first_ok`), but the skipped errors are collected:
+    /// if the upstream ends without an `Ok` item, all of them are yielded as a single `Err`
+    /// (nothing, if there were no items).
+    fn first_ok_collect(self) -> FirstOkCollect<Self, Self::Error> {
+        FirstOkCollect::new(self)
+    }
+}
+
+/// Stream for [`first_ok`](`FirstOkStreamExt::first_ok`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct FirstOk<Stream, Error> {
+    #[pin]
+    inner: Stream,
+
+    last_error: Option<Error>,
+    terminated: bool,
+}
+
+/// Stream for [`first_ok_collect`](`FirstOkStreamExt::first_ok_collect`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct FirstOkCollect<Stream, Error> {
+    #[pin]
+    inner: Stream,
+
+    errors: Vec<Error>,
+    terminated: bool,
+}
+
+impl<S> FirstOk<S, S::Error>
+where
+    S: TryStream,
+{
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            last_error: None,
+            terminated: false,
+        }
+    }
+}
+
+impl<S> FirstOkCollect<S, S::Error>
+where
+    S: TryStream,
+{
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            errors: Vec::new(),
+            terminated: false,
+        }
+    }
+}
+
+impl<S> Stream for FirstOk<S, S::Error>
+where
+    S: Stream + TryStream,
+{
+    type Item = Result<S::Ok, S::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        if *this.terminated {
+            return Poll::Ready(None);
+        }
+
+        Poll::Ready(loop {
+            match ready!(this.inner.as_mut().try_poll_next(cx)) {
+                Some(Err(error)) => *this.last_error = Some(error),
+                Some(Ok(item)) => {
+                    *this.terminated = true;
+                    *this.last_error = None;
+                    break Some(Ok(item));
+                }
+                None => {
+                    *this.terminated = true;
+                    break this.last_error.take().map(Err);
+                }
+            }
+        })
+    }
+}
+
+impl<S> Stream for FirstOkCollect<S, S::Error>
+where
+    S: Stream + TryStream,
+{
+    type Item = Result<S::Ok, Vec<S::Error>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        if *this.terminated {
+            return Poll::Ready(None);
+        }
+
+        Poll::Ready(loop {
+            match ready!(this.inner.as_mut().try_poll_next(cx)) {
+                Some(Err(error)) => this.errors.push(error),
+                Some(Ok(item)) => {
+                    *this.terminated = true;
+                    this.errors.clear();
+                    break Some(Ok(item));
+                }
+                None => {
+                    *this.terminated = true;
+                    let errors = std::mem::take(this.errors);
+                    break (!errors.is_empty()).then_some(Err(errors));
+                }
+            }
+        })
+    }
+}
+
+impl<S> FirstOkStreamExt for S where S: Stream + TryStream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn leading_errors_are_skipped() {
+        assert_eq!(
+            stream::iter([Err("a"), Err("b"), Ok(3), Ok(4)])
+                .first_ok()
+                .collect::<Vec<_>>()
+                .await,
+            vec![Ok(3)]
+        );
+    }
+
+    #[tokio::test]
+    async fn the_last_error_is_yielded_without_an_ok() {
+        assert_eq!(
+            stream::iter([Err::<i32, _>("a"), Err("b")])
+                .first_ok()
+                .collect::<Vec<_>>()
+                .await,
+            vec![Err("b")]
+        );
+        assert!(stream::empty::<Result<i32, ()>>()
+            .first_ok()
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn collected_errors_are_yielded_without_an_ok() {
+        assert_eq!(
+            stream::iter([Err("a"), Err("b"), Ok(3), Ok(4)])
+                .first_ok_collect()
+                .collect::<Vec<_>>()
+                .await,
+            vec![Ok(3)]
+        );
+        assert_eq!(
+            stream::iter([Err::<i32, _>("a"), Err("b")])
+                .first_ok_collect()
+                .collect::<Vec<_>>()
+                .await,
+            vec![Err(vec!["a", "b"])]
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait FlatMapIterStreamExt: Stream + Sized {
+    /// Map each item into an iterator and yield all of its elements before advancing the upstream.
+    ///
+    /// This is a synchronous analog of [`flat_map`](`futures::StreamExt::flat_map`).
+    fn flat_map_iter<F, I>(self, f: F) -> FlatMapIter<Self, F, I::IntoIter>
+    where
+        F: FnMut(Self::Item) -> I,
+        I: IntoIterator,
+    {
+        FlatMapIter::new(self, f)
+    }
+}
+
+/// Stream for [`flat_map_iter`](`FlatMapIterStreamExt::flat_map_iter`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct FlatMapIter<Stream, F, Iter> {
+    #[pin]
+    inner: Stream,
+    f: F,
+
+    current: Option<Iter>,
+}
+
+impl<S, F, It> FlatMapIter<S, F, It> {
+    pub fn new(inner: S, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            current: None,
+        }
+    }
+}
+
+impl<S, F, I> Stream for FlatMapIter<S, F, I::IntoIter>
+where
+    S: Stream,
+    F: FnMut(S::Item) -> I,
+    I: IntoIterator,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            if let Some(item) = this.current.as_mut().and_then(Iterator::next) {
+                break Some(item);
+            }
+            *this.current = None;
+
+            match ready!(this.inner.as_mut().poll_next(cx)) {
+                None => break None,
+                Some(item) => *this.current = Some((this.f)(item).into_iter()),
+            }
+        })
+    }
+}
+
+impl<S> FlatMapIterStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<i32>()
+            .flat_map_iter(|x| [x, x])
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn each_iterator_is_exhausted_in_order() {
+        assert_eq!(
+            stream::iter([1, 2])
+                .flat_map_iter(|x| 0..x)
+                .collect::<Vec<_>>()
+                .await,
+            vec![0, 0, 1]
+        );
+    }
+
+    #[tokio::test]
+    async fn empty_iterators_are_skipped() {
+        assert_eq!(
+            stream::iter([0, 2, 0, 0, 1])
+                .flat_map_iter(|x| vec![x; x])
+                .collect::<Vec<_>>()
+                .await,
+            vec![2, 2, 1]
+        );
+    }
+}
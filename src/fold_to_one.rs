@@ -0,0 +1,236 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{stream::FusedStream, Stream, TryStream};
+
+pub trait FoldToOneStreamExt: Stream + Sized {
+    /// Fold the whole stream with `f` starting from `init`, yielding the final accumulator once the upstream ends.
+    ///
+    /// Exactly one item is yielded, `init` itself for an empty upstream.
+    fn fold_to_one<A, F>(self, init: A, f: F) -> FoldToOne<Self, F, A>
+    where
+        F: FnMut(A, Self::Item) -> A,
+    {
+        FoldToOne::new(self, init, f)
+    }
+}
+
+pub trait TryFoldToOneStreamExt: Stream + TryStream + Sized {
+    /// Similar to [`fold_to_one`](`FoldToOneStreamExt::fold_to_one`) but for `TryStream`.
+    ///
+    /// The first error is yielded instead of the accumulator, ending the stream.
+    fn try_fold_to_one<A, F>(self, init: A, f: F) -> TryFoldToOne<Self, F, A>
+    where
+        F: FnMut(A, Self::Ok) -> A,
+    {
+        TryFoldToOne::new(self, init, f)
+    }
+}
+
+/// Stream for [`fold_to_one`](`FoldToOneStreamExt::fold_to_one`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct FoldToOne<Stream, F, A> {
+    #[pin]
+    inner: Stream,
+    f: F,
+
+    acc: Option<A>,
+}
+
+/// Stream for [`try_fold_to_one`](`TryFoldToOneStreamExt::try_fold_to_one`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct TryFoldToOne<Stream, F, A> {
+    #[pin]
+    inner: Stream,
+    f: F,
+
+    acc: Option<A>,
+}
+
+impl<S, F, A> FoldToOne<S, F, A> {
+    pub fn new(inner: S, init: A, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            acc: Some(init),
+        }
+    }
+}
+
+impl<S, F, A> TryFoldToOne<S, F, A> {
+    pub fn new(inner: S, init: A, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            acc: Some(init),
+        }
+    }
+}
+
+impl<S, F, A> Stream for FoldToOne<S, F, A>
+where
+    S: Stream,
+    F: FnMut(A, S::Item) -> A,
+{
+    type Item = A;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        if this.acc.is_none() {
+            return Poll::Ready(None);
+        }
+
+        Poll::Ready(loop {
+            let Some(item) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                break this.acc.take();
+            };
+            let acc = this
+                .acc
+                .take()
+                .expect("the accumulator is set until the end");
+            *this.acc = Some((this.f)(acc, item));
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = usize::from(self.acc.is_some());
+        (n, Some(n))
+    }
+}
+
+impl<S, F, A> Stream for TryFoldToOne<S, F, A>
+where
+    S: Stream + TryStream,
+    F: FnMut(A, S::Ok) -> A,
+{
+    type Item = Result<A, S::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        if this.acc.is_none() {
+            return Poll::Ready(None);
+        }
+
+        Poll::Ready(loop {
+            match ready!(this.inner.as_mut().try_poll_next(cx)) {
+                None => break this.acc.take().map(Ok),
+                Some(Err(error)) => {
+                    *this.acc = None;
+                    break Some(Err(error));
+                }
+                Some(Ok(item)) => {
+                    let acc = this
+                        .acc
+                        .take()
+                        .expect("the accumulator is set until the end");
+                    *this.acc = Some((this.f)(acc, item));
+                }
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = usize::from(self.acc.is_some());
+        (n, Some(n))
+    }
+}
+
+impl<S, F, A> FusedStream for FoldToOne<S, F, A>
+where
+    S: Stream,
+    F: FnMut(A, S::Item) -> A,
+{
+    fn is_terminated(&self) -> bool {
+        self.acc.is_none()
+    }
+}
+
+impl<S, F, A> FusedStream for TryFoldToOne<S, F, A>
+where
+    S: Stream + TryStream,
+    F: FnMut(A, S::Ok) -> A,
+{
+    fn is_terminated(&self) -> bool {
+        self.acc.is_none()
+    }
+}
+
+impl<S> FoldToOneStreamExt for S where S: Stream + Sized {}
+
+impl<S> TryFoldToOneStreamExt for S where S: Stream + TryStream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{channel::mpsc, poll, stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn a_finite_stream_is_summed_into_one_item() {
+        assert_eq!(
+            stream::iter(1..=4)
+                .fold_to_one(0, |sum, n| sum + n)
+                .collect::<Vec<_>>()
+                .await,
+            vec![10]
+        );
+    }
+
+    #[tokio::test]
+    async fn nothing_is_emitted_before_the_end() {
+        let (tx, rx) = mpsc::unbounded();
+        let mut folded = rx.fold_to_one(0, |sum, n| sum + n);
+
+        tx.unbounded_send(1).unwrap();
+        tx.unbounded_send(2).unwrap();
+        assert_eq!(poll!(folded.next()), Poll::Pending);
+
+        drop(tx);
+        assert_eq!(poll!(folded.next()), Poll::Ready(Some(3)));
+        assert_eq!(poll!(folded.next()), Poll::Ready(None));
+        assert!(folded.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn an_empty_stream_yields_init() {
+        assert_eq!(
+            stream::empty::<i32>()
+                .fold_to_one(7, |sum, n| sum + n)
+                .collect::<Vec<_>>()
+                .await,
+            vec![7]
+        );
+    }
+
+    #[tokio::test]
+    async fn a_clean_try_stream_yields_the_accumulator() {
+        assert_eq!(
+            stream::iter([Ok::<_, ()>(1), Ok(2), Ok(3)])
+                .try_fold_to_one(0, |sum, n| sum + n)
+                .collect::<Vec<_>>()
+                .await,
+            vec![Ok(6)]
+        );
+    }
+
+    #[tokio::test]
+    async fn the_first_error_is_yielded_instead() {
+        assert_eq!(
+            stream::iter([Ok(1), Err("first"), Ok(3), Err("second")])
+                .try_fold_to_one(0, |sum, n| sum + n)
+                .collect::<Vec<_>>()
+                .await,
+            vec![Err("first")]
+        );
+    }
+}
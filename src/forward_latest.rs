@@ -0,0 +1,213 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{Sink, Stream};
+
+use crate::drain;
+
+pub trait ForwardLatestStreamExt: Stream + Sized {
+    /// Send the items into `sink`, conflating them to the latest one while the sink is not ready.
+    ///
+    /// The stream keeps being drained while `poll_ready` is pending, a bounded number of items per poll,
+    /// and only the most recent item is sent once the sink becomes ready; the earlier ones are dropped.
+    /// The sink is flushed whenever the stream is pending, and closed once the stream ends.
+    fn forward_latest<Si>(self, sink: Si) -> ForwardLatest<Self, Si, Self::Item>
+    where
+        Si: Sink<Self::Item>,
+    {
+        ForwardLatest::new(self, sink)
+    }
+}
+
+/// Future for [`forward_latest`](`ForwardLatestStreamExt::forward_latest`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ForwardLatest<Stream, Si, Item> {
+    #[pin]
+    stream: Stream,
+    #[pin]
+    sink: Si,
+    stream_done: bool,
+
+    latest: Option<Item>,
+}
+
+impl<S, Si> ForwardLatest<S, Si, S::Item>
+where
+    S: Stream,
+{
+    pub fn new(stream: S, sink: Si) -> Self {
+        Self {
+            stream,
+            sink,
+            stream_done: false,
+            latest: None,
+        }
+    }
+}
+
+impl<S, Si> Future for ForwardLatest<S, Si, S::Item>
+where
+    S: Stream,
+    Si: Sink<S::Item>,
+{
+    type Output = Result<(), Si::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        loop {
+            if this.latest.is_some() {
+                match this.sink.as_mut().poll_ready(cx)? {
+                    Poll::Ready(()) => {
+                        let item = this.latest.take().expect("checked above");
+                        this.sink.as_mut().start_send(item)?;
+                    }
+                    Poll::Pending => {
+                        let mut budget = drain::BUDGET;
+                        while !*this.stream_done {
+                            if budget == 0 {
+                                cx.waker().wake_by_ref();
+                                break;
+                            }
+                            budget -= 1;
+                            match this.stream.as_mut().poll_next(cx) {
+                                Poll::Pending => break,
+                                Poll::Ready(None) => *this.stream_done = true,
+                                Poll::Ready(Some(item)) => *this.latest = Some(item),
+                            }
+                        }
+                        return Poll::Pending;
+                    }
+                }
+            }
+
+            if *this.stream_done {
+                ready!(this.sink.as_mut().poll_close(cx))?;
+                return Poll::Ready(Ok(()));
+            }
+
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => *this.latest = Some(item),
+                Poll::Ready(None) => *this.stream_done = true,
+                Poll::Pending => {
+                    ready!(this.sink.as_mut().poll_flush(cx))?;
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+impl<S> ForwardLatestStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use futures::{stream, StreamExt};
+
+    use crate::test_utils::ready_after_n_polls;
+
+    use super::*;
+
+    /// A sink that needs `delay` polls of `poll_ready` before accepting each next item.
+    struct SlowSink {
+        delay: usize,
+        not_ready_for: usize,
+        received: Vec<i32>,
+    }
+
+    impl SlowSink {
+        fn new(delay: usize) -> Self {
+            Self {
+                delay,
+                not_ready_for: 0,
+                received: vec![],
+            }
+        }
+    }
+
+    impl Sink<i32> for SlowSink {
+        type Error = Infallible;
+
+        fn poll_ready(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            if let Some(left) = self.not_ready_for.checked_sub(1) {
+                self.not_ready_for = left;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            } else {
+                Poll::Ready(Ok(()))
+            }
+        }
+
+        fn start_send(mut self: Pin<&mut Self>, item: i32) -> Result<(), Self::Error> {
+            self.received.push(item);
+            self.not_ready_for = self.delay;
+            Ok(())
+        }
+
+        fn poll_flush(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn a_ready_sink_receives_everything() {
+        let mut sink = SlowSink::new(0);
+        stream::iter(1..=5).forward_latest(&mut sink).await.unwrap();
+        assert_eq!(sink.received, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn a_slow_sink_receives_only_the_latest_items() {
+        let mut sink = SlowSink::new(2);
+        stream::iter(1..=10)
+            .forward_latest(&mut sink)
+            .await
+            .unwrap();
+        assert_eq!(sink.received, vec![1, 10]);
+    }
+
+    #[tokio::test]
+    async fn items_are_conflated_while_the_sink_is_busy() {
+        let mut sink = SlowSink::new(3);
+        stream::iter([1, 2, 3, 4, 5, 6])
+            .then(|item| ready_after_n_polls(item, 1))
+            .forward_latest(&mut sink)
+            .await
+            .unwrap();
+        assert_eq!(sink.received, vec![1, 4, 6]);
+    }
+
+    #[tokio::test]
+    async fn an_always_ready_stream_does_not_hang_a_busy_sink() {
+        use futures::poll;
+
+        let mut sink = SlowSink::new(usize::MAX);
+        let mut forward = stream::iter(0..).forward_latest(&mut sink);
+        assert_eq!(poll!(&mut forward), Poll::Pending);
+        assert_eq!(forward.latest, Some(1 + drain::BUDGET as i32));
+        drop(forward);
+        assert_eq!(sink.received, vec![0]);
+    }
+}
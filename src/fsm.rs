@@ -0,0 +1,152 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+pub use smallvec::SmallVec;
+
+pub trait FsmStreamExt: Stream + Sized {
+    /// Run the items through a state machine: `f` updates the state and returns the outputs produced by each item.
+    ///
+    /// The outputs of an item are yielded in order before the next item is pulled. This generalizes
+    /// [`scan`](`futures::StreamExt::scan`) to zero or more outputs per item.
+    fn fsm<St, F, T>(self, init: St, f: F) -> Fsm<Self, St, F, T>
+    where
+        F: FnMut(&mut St, Self::Item) -> SmallVec<[T; 1]>,
+    {
+        Fsm::new(self, init, f)
+    }
+}
+
+/// Stream for [`fsm`](`FsmStreamExt::fsm`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct Fsm<Stream, St, F, T> {
+    #[pin]
+    inner: Stream,
+    f: F,
+
+    state: St,
+    outputs: smallvec::IntoIter<[T; 1]>,
+}
+
+impl<S, St, F, T> Fsm<S, St, F, T> {
+    pub fn new(inner: S, init: St, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            state: init,
+            outputs: SmallVec::new().into_iter(),
+        }
+    }
+}
+
+impl<S, St, F, T> Stream for Fsm<S, St, F, T>
+where
+    S: Stream,
+    F: FnMut(&mut St, S::Item) -> SmallVec<[T; 1]>,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            if let Some(output) = this.outputs.next() {
+                break Some(output);
+            }
+            let Some(item) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                break None;
+            };
+            *this.outputs = (this.f)(this.state, item).into_iter();
+        })
+    }
+}
+
+impl<S> FsmStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+    use smallvec::smallvec;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Token {
+        Number(u32),
+        Op(char),
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    enum Lexer {
+        Idle,
+        InNumber(u32),
+    }
+
+    fn lex(state: &mut Lexer, c: char) -> SmallVec<[Token; 1]> {
+        match (*state, c.to_digit(10)) {
+            (Lexer::Idle, Some(d)) => {
+                *state = Lexer::InNumber(d);
+                smallvec![]
+            }
+            (Lexer::InNumber(n), Some(d)) => {
+                *state = Lexer::InNumber(n * 10 + d);
+                smallvec![]
+            }
+            (Lexer::Idle, None) => smallvec![Token::Op(c)],
+            (Lexer::InNumber(n), None) => {
+                *state = Lexer::Idle;
+                smallvec![Token::Number(n), Token::Op(c)]
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<char>()
+            .fsm(Lexer::Idle, lex)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn two_state_lexer() {
+        use Token::*;
+
+        assert_eq!(
+            stream::iter("12+3*(45);".chars())
+                .fsm(Lexer::Idle, lex)
+                .collect::<Vec<_>>()
+                .await,
+            vec![
+                Number(12),
+                Op('+'),
+                Number(3),
+                Op('*'),
+                Op('('),
+                Number(45),
+                Op(')'),
+                Op(';')
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn state_is_threaded_through() {
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .fsm(0, |total, x| {
+                    *total += x;
+                    smallvec![*total]
+                })
+                .collect::<Vec<_>>()
+                .await,
+            vec![1, 3, 6]
+        );
+    }
+}
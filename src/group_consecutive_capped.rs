@@ -0,0 +1,137 @@
+use std::{
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait GroupConsecutiveCappedStreamExt: Stream + Sized {
+    /// Group the consecutive items with equal keys into `Vec`s of at most `max` items.
+    ///
+    /// A group is yielded when the key changes, when it reaches `max` items, or when the upstream ends.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max` is zero.
+    fn group_consecutive_capped<K, F>(
+        self,
+        f: F,
+        max: usize,
+    ) -> GroupConsecutiveCapped<Self, F, K, Self::Item>
+    where
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq,
+    {
+        GroupConsecutiveCapped::new(self, f, max)
+    }
+}
+
+/// Stream for [`group_consecutive_capped`](`GroupConsecutiveCappedStreamExt::group_consecutive_capped`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct GroupConsecutiveCapped<Stream, F, K, Item> {
+    #[pin]
+    inner: Stream,
+    f: F,
+    max: usize,
+
+    key: Option<K>,
+    group: Vec<Item>,
+    done: bool,
+}
+
+impl<S, F, K, I> GroupConsecutiveCapped<S, F, K, I> {
+    pub fn new(inner: S, f: F, max: usize) -> Self {
+        assert!(max > 0, "the group size must not be zero");
+        Self {
+            inner,
+            f,
+            max,
+            key: None,
+            group: Vec::new(),
+            done: false,
+        }
+    }
+}
+
+impl<S, F, K> Stream for GroupConsecutiveCapped<S, F, K, S::Item>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> K,
+    K: PartialEq,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        Poll::Ready(loop {
+            let Some(item) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                *this.done = true;
+                *this.key = None;
+                break Some(mem::take(this.group)).filter(|group| !group.is_empty());
+            };
+
+            let key = (this.f)(&item);
+            if this.key.as_ref().is_some_and(|current| *current != key) {
+                let group = mem::replace(this.group, vec![item]);
+                *this.key = Some(key);
+                break Some(group);
+            }
+
+            *this.key = Some(key);
+            this.group.push(item);
+            if this.group.len() == *this.max {
+                *this.key = None;
+                break Some(mem::take(this.group));
+            }
+        })
+    }
+}
+
+impl<S> GroupConsecutiveCappedStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn groups_are_split_on_key_change() {
+        assert_eq!(
+            stream::iter([1, 3, 2, 4, 6, 5])
+                .group_consecutive_capped(|n| n % 2, 10)
+                .collect::<Vec<_>>()
+                .await,
+            vec![vec![1, 3], vec![2, 4, 6], vec![5]]
+        );
+    }
+
+    #[tokio::test]
+    async fn long_runs_are_split_at_max() {
+        assert_eq!(
+            stream::iter([0; 7].into_iter().chain([1, 1]))
+                .group_consecutive_capped(|n| *n, 3)
+                .collect::<Vec<_>>()
+                .await,
+            vec![vec![0, 0, 0], vec![0, 0, 0], vec![0], vec![1, 1]]
+        );
+    }
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<i32>()
+            .group_consecutive_capped(|n| *n, 3)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+}
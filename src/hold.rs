@@ -0,0 +1,129 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{stream::FusedStream, Stream};
+
+use crate::expand::Expand;
+
+pub trait HoldStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: Clone,
+{
+    /// Similar to [`expand`](`crate::expand::ExpandStreamExt::expand`), but yields `initial` while the upstream
+    /// is pending before its first item.
+    ///
+    /// The stream still ends as soon as the upstream does, even if it has not produced any items.
+    fn hold(self, initial: Self::Item) -> Hold<Self, Self::Item> {
+        Hold::new(self, initial)
+    }
+}
+
+/// Stream for [`hold`](`HoldStreamExt::hold`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct Hold<Stream, Item> {
+    #[pin]
+    inner: Expand<Stream, Item>,
+}
+
+impl<S> Hold<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    pub fn new(inner: S, initial: S::Item) -> Self {
+        Self {
+            inner: Expand::seeded(inner, initial),
+        }
+    }
+}
+
+impl<S> Stream for Hold<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().inner.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S> FusedStream for Hold<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<S> HoldStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+    use crate::test_utils::ready_after_n_polls;
+
+    #[tokio::test]
+    async fn empty_stream_immediately_ends() {
+        assert!(stream::empty::<i32>()
+            .hold(0)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn the_initial_item_is_held_before_the_first_one() {
+        assert_eq!(
+            stream::once(ready_after_n_polls(1, 2))
+                .chain(stream::iter([2, 3]))
+                .hold(0)
+                .collect::<Vec<_>>()
+                .await,
+            vec![0, 0, 1, 2, 3]
+        );
+    }
+
+    #[tokio::test]
+    async fn the_latest_item_is_held_afterwards() {
+        assert_eq!(
+            stream::iter([1, 2])
+                .chain(stream::once(ready_after_n_polls(3, 2)))
+                .hold(0)
+                .collect::<Vec<_>>()
+                .await,
+            vec![1, 2, 2, 2, 3]
+        );
+    }
+
+    #[tokio::test]
+    async fn a_pending_empty_upstream_still_ends() {
+        assert_eq!(
+            stream::once(ready_after_n_polls((), 2))
+                .filter(|_| async { false })
+                .map(|()| 1)
+                .hold(0)
+                .collect::<Vec<_>>()
+                .await,
+            vec![0, 0]
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use std::{
+    ops::Sub,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait HysteresisStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: PartialOrd + Sub<Output = Self::Item> + Clone,
+{
+    /// Emit the first item, then only the items that differ from the last emitted one by more than `band`.
+    fn hysteresis(self, band: Self::Item) -> Hysteresis<Self, Self::Item> {
+        Hysteresis::new(self, band)
+    }
+}
+
+/// Stream for [`hysteresis`](`HysteresisStreamExt::hysteresis`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct Hysteresis<Stream, Item> {
+    #[pin]
+    inner: Stream,
+    band: Item,
+
+    last_emitted: Option<Item>,
+}
+
+impl<S> Hysteresis<S, S::Item>
+where
+    S: Stream,
+    S::Item: PartialOrd + Sub<Output = S::Item> + Clone,
+{
+    pub fn new(inner: S, band: S::Item) -> Self {
+        Self {
+            inner,
+            band,
+            last_emitted: None,
+        }
+    }
+}
+
+impl<S> Stream for Hysteresis<S, S::Item>
+where
+    S: Stream,
+    S::Item: PartialOrd + Sub<Output = S::Item> + Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            let Some(item) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                break None;
+            };
+
+            let moved = match this.last_emitted.as_ref() {
+                None => true,
+                Some(last) if item > *last => item.clone() - last.clone() > *this.band,
+                Some(last) => last.clone() - item.clone() > *this.band,
+            };
+            if moved {
+                *this.last_emitted = Some(item.clone());
+                break Some(item);
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        (lower.min(1), upper)
+    }
+}
+
+impl<S> HysteresisStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: PartialOrd + Sub<Output = S::Item> + Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn small_moves_are_suppressed_and_steps_pass() {
+        assert_eq!(
+            stream::iter([10.0, 10.2, 9.9, 10.4, 12.0, 11.7, 12.3, 8.0, 8.5, 7.8])
+                .hysteresis(0.5)
+                .collect::<Vec<_>>()
+                .await,
+            vec![10.0, 12.0, 8.0]
+        );
+    }
+
+    #[tokio::test]
+    async fn the_band_is_measured_from_the_last_emitted_value() {
+        assert_eq!(
+            stream::iter([0, 2, 4, 6, 8])
+                .hysteresis(3)
+                .collect::<Vec<_>>()
+                .await,
+            vec![0, 4, 8]
+        );
+    }
+}
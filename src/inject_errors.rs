@@ -0,0 +1,110 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{Stream, TryStream};
+
+pub trait InjectErrorsStreamExt: Stream + TryStream + Sized {
+    /// Pass a fallible stream through, injecting `make_err()` after every `every` upstream `Ok` items.
+    ///
+    /// Meant for exercising the error handling downstream; the upstream errors pass through uncounted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `every` is zero.
+    fn inject_errors<F>(self, every: usize, make_err: F) -> InjectErrors<Self, F>
+    where
+        F: FnMut() -> Self::Error,
+    {
+        InjectErrors::new(self, every, make_err)
+    }
+}
+
+/// Stream for [`inject_errors`](`InjectErrorsStreamExt::inject_errors`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct InjectErrors<Stream, F> {
+    #[pin]
+    inner: Stream,
+    every: usize,
+    make_err: F,
+
+    since_injected: usize,
+}
+
+impl<S, F> InjectErrors<S, F> {
+    pub fn new(inner: S, every: usize, make_err: F) -> Self {
+        assert!(every > 0, "the period must not be zero");
+        Self {
+            inner,
+            every,
+            make_err,
+            since_injected: 0,
+        }
+    }
+}
+
+impl<S, F> Stream for InjectErrors<S, F>
+where
+    S: Stream + TryStream,
+    F: FnMut() -> S::Error,
+{
+    type Item = Result<S::Ok, S::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        if *this.since_injected == *this.every {
+            *this.since_injected = 0;
+            return Poll::Ready(Some(Err((this.make_err)())));
+        }
+
+        let item = ready!(this.inner.try_poll_next(cx));
+        if let Some(Ok(_)) = item {
+            *this.since_injected += 1;
+        }
+        Poll::Ready(item)
+    }
+}
+
+impl<S> InjectErrorsStreamExt for S where S: Stream + TryStream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn errors_follow_every_nth_item() {
+        assert_eq!(
+            stream::iter([Ok(1), Ok(2), Ok(3), Ok(4), Ok(5)])
+                .inject_errors(2, || "injected")
+                .collect::<Vec<_>>()
+                .await,
+            vec![
+                Ok(1),
+                Ok(2),
+                Err("injected"),
+                Ok(3),
+                Ok(4),
+                Err("injected"),
+                Ok(5),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn upstream_errors_are_not_counted() {
+        assert_eq!(
+            stream::iter([Ok(1), Err("real"), Ok(2), Ok(3)])
+                .inject_errors(2, || "injected")
+                .collect::<Vec<_>>()
+                .await,
+            vec![Ok(1), Err("real"), Ok(2), Err("injected"), Ok(3)]
+        );
+    }
+}
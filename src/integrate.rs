@@ -0,0 +1,110 @@
+use std::{
+    ops::Add,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait IntegrateStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: Add<Output = Self::Item> + Clone,
+{
+    /// Turn a stream of increments into a stream of running totals starting from `base`.
+    ///
+    /// The first item is `base + delta_0`; `base` itself is not emitted.
+    fn integrate(self, base: Self::Item) -> Integrate<Self, Self::Item> {
+        Integrate::new(self, base)
+    }
+}
+
+/// Stream for [`integrate`](`IntegrateStreamExt::integrate`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct Integrate<Stream, Item> {
+    #[pin]
+    inner: Stream,
+
+    total: Item,
+}
+
+impl<S> Integrate<S, S::Item>
+where
+    S: Stream,
+    S::Item: Add<Output = S::Item> + Clone,
+{
+    pub fn new(inner: S, base: S::Item) -> Self {
+        Self { inner, total: base }
+    }
+}
+
+impl<S> Stream for Integrate<S, S::Item>
+where
+    S: Stream,
+    S::Item: Add<Output = S::Item> + Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        let Some(delta) = ready!(this.inner.poll_next(cx)) else {
+            return Poll::Ready(None);
+        };
+        *this.total = this.total.clone() + delta;
+
+        Poll::Ready(Some(this.total.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S> IntegrateStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Add<Output = S::Item> + Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<i32>()
+            .integrate(0)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn running_totals_from_zero() {
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .integrate(0)
+                .collect::<Vec<_>>()
+                .await,
+            vec![1, 3, 6]
+        );
+    }
+
+    #[tokio::test]
+    async fn running_totals_from_a_base() {
+        assert_eq!(
+            stream::iter([0.5, -1.5, 2.0])
+                .integrate(10.0)
+                .collect::<Vec<_>>()
+                .await,
+            vec![10.5, 9.0, 11.0]
+        );
+    }
+}
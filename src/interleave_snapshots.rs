@@ -0,0 +1,125 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait InterleaveSnapshotsStreamExt: Stream + Sized {
+    /// Pass the items through, emitting `snapshot()` after every `every` items.
+    ///
+    /// The snapshot is taken lazily, when it is about to be emitted. `every == 0` disables the snapshots.
+    fn interleave_snapshots<F, S>(self, every: usize, snapshot: F) -> InterleaveSnapshots<Self, F>
+    where
+        F: FnMut() -> S,
+    {
+        InterleaveSnapshots::new(self, every, snapshot)
+    }
+}
+
+/// Either an upstream item or an out-of-band snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mixed<T, S> {
+    Data(T),
+    Snapshot(S),
+}
+
+/// Stream for [`interleave_snapshots`](`InterleaveSnapshotsStreamExt::interleave_snapshots`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct InterleaveSnapshots<Stream, F> {
+    #[pin]
+    inner: Stream,
+    every: usize,
+    snapshot: F,
+
+    since_snapshot: usize,
+}
+
+impl<S, F> InterleaveSnapshots<S, F> {
+    pub fn new(inner: S, every: usize, snapshot: F) -> Self {
+        Self {
+            inner,
+            every,
+            snapshot,
+            since_snapshot: 0,
+        }
+    }
+}
+
+impl<S, F, Sn> Stream for InterleaveSnapshots<S, F>
+where
+    S: Stream,
+    F: FnMut() -> Sn,
+{
+    type Item = Mixed<S::Item, Sn>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        if *this.every > 0 && *this.since_snapshot == *this.every {
+            *this.since_snapshot = 0;
+            return Poll::Ready(Some(Mixed::Snapshot((this.snapshot)())));
+        }
+
+        let item = ready!(this.inner.poll_next(cx));
+        if item.is_some() {
+            *this.since_snapshot += 1;
+        }
+        Poll::Ready(item.map(Mixed::Data))
+    }
+}
+
+impl<S> InterleaveSnapshotsStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<()>()
+            .interleave_snapshots(1, || ())
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn snapshots_follow_every_nth_item() {
+        let mut taken = 0;
+        assert_eq!(
+            stream::iter(1..=5)
+                .interleave_snapshots(2, || {
+                    taken += 1;
+                    taken
+                })
+                .collect::<Vec<_>>()
+                .await,
+            vec![
+                Mixed::Data(1),
+                Mixed::Data(2),
+                Mixed::Snapshot(1),
+                Mixed::Data(3),
+                Mixed::Data(4),
+                Mixed::Snapshot(2),
+                Mixed::Data(5),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn zero_disables_snapshots() {
+        assert_eq!(
+            stream::iter(1..=3)
+                .interleave_snapshots(0, || "snapshot")
+                .collect::<Vec<_>>()
+                .await,
+            vec![Mixed::Data(1), Mixed::Data(2), Mixed::Data(3)]
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+use tokio::sync::mpsc::{
+    error::{SendError, TrySendError},
+    OwnedPermit, Sender,
+};
+
+use crate::drain;
+
+pub trait IntoMpscLatestStreamExt: Stream + Sized
+where
+    Self::Item: Send + 'static,
+{
+    /// Forward the items into `tx`, conflating them to the latest one while the channel is full.
+    ///
+    /// The upstream is drained without waiting for the channel, a bounded number of items per poll: an item
+    /// that does not fit is held, replaced by any newer one, and sent once there is capacity again. The future
+    /// completes once the upstream has ended and the held item has been sent, or once the channel is closed.
+    fn into_mpsc_latest(self, tx: Sender<Self::Item>) -> IntoMpscLatest<Self, Self::Item> {
+        IntoMpscLatest::new(self, tx)
+    }
+}
+
+type Reserve<T> = Pin<Box<dyn Future<Output = Result<OwnedPermit<T>, SendError<()>>> + Send>>;
+
+/// Future for [`into_mpsc_latest`](`IntoMpscLatestStreamExt::into_mpsc_latest`) method.
+#[pin_project::pin_project]
+pub struct IntoMpscLatest<Stream, Item> {
+    #[pin]
+    inner: Stream,
+    tx: Sender<Item>,
+
+    held: Option<Item>,
+    reserve: Option<Reserve<Item>>,
+    inner_done: bool,
+}
+
+impl<S, T> std::fmt::Debug for IntoMpscLatest<S, T>
+where
+    S: std::fmt::Debug,
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IntoMpscLatest")
+            .field("inner", &self.inner)
+            .field("tx", &self.tx)
+            .field("held", &self.held)
+            .field("inner_done", &self.inner_done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> IntoMpscLatest<S, S::Item>
+where
+    S: Stream,
+    S::Item: Send + 'static,
+{
+    pub fn new(inner: S, tx: Sender<S::Item>) -> Self {
+        Self {
+            inner,
+            tx,
+            held: None,
+            reserve: None,
+            inner_done: false,
+        }
+    }
+}
+
+impl<S> Future for IntoMpscLatest<S, S::Item>
+where
+    S: Stream,
+    S::Item: Send + 'static,
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        let mut budget = drain::BUDGET;
+        loop {
+            while !*this.inner_done {
+                if budget == 0 {
+                    cx.waker().wake_by_ref();
+                    break;
+                }
+                budget -= 1;
+                match this.inner.as_mut().poll_next(cx) {
+                    Poll::Pending => break,
+                    Poll::Ready(None) => *this.inner_done = true,
+                    Poll::Ready(Some(item)) if this.held.is_some() => *this.held = Some(item),
+                    Poll::Ready(Some(item)) => match this.tx.try_send(item) {
+                        Ok(()) => (),
+                        Err(TrySendError::Full(item)) => *this.held = Some(item),
+                        Err(TrySendError::Closed(_)) => return Poll::Ready(()),
+                    },
+                }
+            }
+
+            if this.held.is_none() {
+                return if *this.inner_done {
+                    Poll::Ready(())
+                } else {
+                    Poll::Pending
+                };
+            }
+
+            let reserve = this
+                .reserve
+                .get_or_insert_with(|| Box::pin(this.tx.clone().reserve_owned()));
+            let Poll::Ready(permit) = reserve.as_mut().poll(cx) else {
+                return Poll::Pending;
+            };
+            *this.reserve = None;
+            let Ok(permit) = permit else {
+                return Poll::Ready(());
+            };
+            permit.send(this.held.take().expect("an item is held"));
+            if budget == 0 {
+                // The task has woken itself once the budget ran out.
+                return Poll::Pending;
+            }
+        }
+    }
+}
+
+impl<S> IntoMpscLatestStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Send + 'static,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{channel::mpsc as futures_mpsc, poll, stream};
+    use tokio::sync::mpsc;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn the_latest_item_wins_while_the_channel_is_full() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let mut forward = Box::pin(stream::iter(1..=5).into_mpsc_latest(tx));
+
+        assert_eq!(poll!(forward.as_mut()), Poll::Pending);
+        assert_eq!(rx.try_recv(), Ok(1));
+        assert_eq!(poll!(forward.as_mut()), Poll::Ready(()));
+        drop(forward);
+        assert_eq!(rx.recv().await, Some(5));
+        assert_eq!(rx.recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn the_upstream_is_drained_while_waiting_for_capacity() {
+        let (tx, mut rx) = mpsc::channel(2);
+        let (source_tx, source_rx) = futures_mpsc::unbounded();
+        let mut forward = Box::pin(source_rx.into_mpsc_latest(tx));
+
+        for n in 1..=4 {
+            source_tx.unbounded_send(n).unwrap();
+        }
+        assert_eq!(poll!(forward.as_mut()), Poll::Pending);
+        assert_eq!(rx.try_recv(), Ok(1));
+        assert_eq!(rx.try_recv(), Ok(2));
+
+        source_tx.unbounded_send(5).unwrap();
+        assert_eq!(poll!(forward.as_mut()), Poll::Pending);
+        assert_eq!(rx.try_recv(), Ok(5));
+
+        source_tx.unbounded_send(6).unwrap();
+        drop(source_tx);
+        assert_eq!(poll!(forward.as_mut()), Poll::Ready(()));
+        drop(forward);
+        assert_eq!(rx.recv().await, Some(6));
+        assert_eq!(rx.recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn a_closed_channel_completes_the_future() {
+        let (tx, rx) = mpsc::channel(1);
+        drop(rx);
+        stream::iter(1..=3).into_mpsc_latest(tx).await;
+    }
+
+    #[tokio::test]
+    async fn an_always_ready_upstream_is_drained_in_bounded_steps() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let mut forward = Box::pin(stream::iter(0..).into_mpsc_latest(tx));
+
+        assert_eq!(poll!(forward.as_mut()), Poll::Pending);
+        assert_eq!(rx.try_recv(), Ok(0));
+        assert_eq!(poll!(forward.as_mut()), Poll::Pending);
+        assert_eq!(rx.try_recv(), Ok(2 * drain::BUDGET - 1));
+    }
+}
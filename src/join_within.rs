@@ -0,0 +1,223 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{stream::FusedStream, Stream};
+
+pub trait JoinWithinStreamExt: Stream + Sized {
+    /// Pair the items of both sides whose timestamps, extracted with `left_ts` and `right_ts`,
+    /// differ by at most `tolerance`.
+    ///
+    /// Each item is paired at most once: an arriving item is matched with the oldest buffered item
+    /// of the other side within `tolerance`, or is buffered itself. Both inputs are expected to be sorted
+    /// by timestamp, so a buffered item is evicted as soon as the other side moves beyond `tolerance` from it.
+    /// The stream ends once no more pairs are possible: both sides have ended, or one has ended
+    /// with nothing left buffered.
+    fn join_within<R, F, G>(
+        self,
+        right: R,
+        tolerance: u64,
+        left_ts: F,
+        right_ts: G,
+    ) -> JoinWithin<Self, R, F, G, Self::Item, R::Item>
+    where
+        R: Stream,
+        F: FnMut(&Self::Item) -> u64,
+        G: FnMut(&R::Item) -> u64,
+    {
+        JoinWithin::new(self, right, tolerance, left_ts, right_ts)
+    }
+}
+
+/// Stream for [`join_within`](`JoinWithinStreamExt::join_within`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct JoinWithin<L, R, F, G, LI, RI> {
+    #[pin]
+    left: L,
+    #[pin]
+    right: R,
+    tolerance: u64,
+    left_ts: F,
+    right_ts: G,
+
+    left_unmatched: VecDeque<(u64, LI)>,
+    right_unmatched: VecDeque<(u64, RI)>,
+    left_done: bool,
+    right_done: bool,
+    terminated: bool,
+}
+
+impl<L, R, F, G, LI, RI> JoinWithin<L, R, F, G, LI, RI> {
+    pub fn new(left: L, right: R, tolerance: u64, left_ts: F, right_ts: G) -> Self {
+        Self {
+            left,
+            right,
+            tolerance,
+            left_ts,
+            right_ts,
+            left_unmatched: VecDeque::new(),
+            right_unmatched: VecDeque::new(),
+            left_done: false,
+            right_done: false,
+            terminated: false,
+        }
+    }
+}
+
+/// Evict the items too old to match `ts` or any later timestamp, then take the oldest one matching `ts`.
+fn take_match<T>(unmatched: &mut VecDeque<(u64, T)>, ts: u64, tolerance: u64) -> Option<T> {
+    while unmatched
+        .front()
+        .is_some_and(|(old, _)| old.saturating_add(tolerance) < ts)
+    {
+        unmatched.pop_front();
+    }
+    let position = unmatched
+        .iter()
+        .position(|(other, _)| other.abs_diff(ts) <= tolerance)?;
+    unmatched.remove(position).map(|(_, item)| item)
+}
+
+impl<L, R, F, G> Stream for JoinWithin<L, R, F, G, L::Item, R::Item>
+where
+    L: Stream,
+    R: Stream,
+    F: FnMut(&L::Item) -> u64,
+    G: FnMut(&R::Item) -> u64,
+{
+    type Item = (L::Item, R::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.terminated {
+            return Poll::Ready(None);
+        }
+
+        Poll::Ready(loop {
+            let mut progressed = false;
+
+            if !*this.left_done {
+                match this.left.as_mut().poll_next(cx) {
+                    Poll::Pending => (),
+                    Poll::Ready(None) => {
+                        *this.left_done = true;
+                        progressed = true;
+                    }
+                    Poll::Ready(Some(item)) => {
+                        progressed = true;
+                        let ts = (this.left_ts)(&item);
+                        if let Some(matched) = take_match(this.right_unmatched, ts, *this.tolerance)
+                        {
+                            break Some((item, matched));
+                        }
+                        this.left_unmatched.push_back((ts, item));
+                    }
+                }
+            }
+            if !*this.right_done {
+                match this.right.as_mut().poll_next(cx) {
+                    Poll::Pending => (),
+                    Poll::Ready(None) => {
+                        *this.right_done = true;
+                        progressed = true;
+                    }
+                    Poll::Ready(Some(item)) => {
+                        progressed = true;
+                        let ts = (this.right_ts)(&item);
+                        if let Some(matched) = take_match(this.left_unmatched, ts, *this.tolerance)
+                        {
+                            break Some((matched, item));
+                        }
+                        this.right_unmatched.push_back((ts, item));
+                    }
+                }
+            }
+
+            let left_exhausted = *this.left_done && this.left_unmatched.is_empty();
+            let right_exhausted = *this.right_done && this.right_unmatched.is_empty();
+            if (*this.left_done && *this.right_done) || left_exhausted || right_exhausted {
+                *this.terminated = true;
+                this.left_unmatched.clear();
+                this.right_unmatched.clear();
+                break None;
+            }
+
+            if !progressed {
+                return Poll::Pending;
+            }
+        })
+    }
+}
+
+impl<L, R, F, G> FusedStream for JoinWithin<L, R, F, G, L::Item, R::Item>
+where
+    L: Stream,
+    R: Stream,
+    F: FnMut(&L::Item) -> u64,
+    G: FnMut(&R::Item) -> u64,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<S> JoinWithinStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn items_within_the_tolerance_are_paired() {
+        let left = stream::iter([(100, 'a'), (200, 'b'), (300, 'c'), (400, 'd')]);
+        let right = stream::iter([(95, "x"), (260, "y"), (310, "z"), (405, "w")]);
+
+        assert_eq!(
+            left.join_within(right, 10, |(ts, _)| *ts, |(ts, _)| *ts)
+                .map(|((_, l), (_, r))| (l, r))
+                .collect::<Vec<_>>()
+                .await,
+            vec![('a', "x"), ('c', "z"), ('d', "w")]
+        );
+    }
+
+    #[tokio::test]
+    async fn each_item_is_paired_at_most_once() {
+        let left = stream::iter([10, 12, 14]);
+        let right = stream::iter([11, 30]);
+
+        assert_eq!(
+            left.join_within(right, 5, |ts| *ts, |ts| *ts)
+                .collect::<Vec<_>>()
+                .await,
+            vec![(10, 11)]
+        );
+    }
+
+    #[tokio::test]
+    async fn unmatched_items_wait_for_the_other_side() {
+        let left = stream::iter([100, 200, 300]);
+        let right = stream::iter([1, 2, 3, 198, 301]);
+
+        assert_eq!(
+            left.join_within(right, 3, |ts| *ts, |ts| *ts)
+                .collect::<Vec<_>>()
+                .await,
+            vec![(200, 198), (300, 301)]
+        );
+    }
+
+    #[test]
+    fn stale_items_are_evicted() {
+        let mut unmatched = VecDeque::from([(10, 'a'), (20, 'b'), (30, 'c')]);
+
+        assert_eq!(take_match(&mut unmatched, 32, 5), Some('c'));
+        assert!(unmatched.is_empty());
+    }
+}
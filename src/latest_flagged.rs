@@ -0,0 +1,172 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+use crate::latest_ready::LatestReady;
+
+pub trait LatestFlaggedStreamExt: Stream + Sized {
+    /// Same as [`latest_ready`](`crate::latest_ready::LatestReadyStreamExt::latest_ready`),
+    /// but each item is paired with an "is latest" flag, which is always `true` here.
+    fn latest_ready_flagged(self) -> LatestReadyFlagged<Self> {
+        LatestReadyFlagged::new(self)
+    }
+
+    /// Pass every item through, paired with an "is latest" flag.
+    ///
+    /// The flag is `false` if another item was already ready behind this one, i.e. the item is stale at emission.
+    /// To find that out the upstream is polled one item ahead.
+    fn passthrough_flagged(self) -> PassthroughFlagged<Self, Self::Item> {
+        PassthroughFlagged::new(self)
+    }
+}
+
+/// Stream for [`latest_ready_flagged`](`LatestFlaggedStreamExt::latest_ready_flagged`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct LatestReadyFlagged<Stream> {
+    #[pin]
+    inner: LatestReady<Stream>,
+}
+
+/// Stream for [`passthrough_flagged`](`LatestFlaggedStreamExt::passthrough_flagged`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct PassthroughFlagged<Stream, Item> {
+    #[pin]
+    inner: Stream,
+    inner_done: bool,
+
+    ahead: Option<Item>,
+}
+
+impl<S> LatestReadyFlagged<S> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner: LatestReady::new(inner),
+        }
+    }
+}
+
+impl<S> PassthroughFlagged<S, S::Item>
+where
+    S: Stream,
+{
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            inner_done: false,
+            ahead: None,
+        }
+    }
+}
+
+impl<S> Stream for LatestReadyFlagged<S>
+where
+    S: Stream,
+{
+    type Item = (S::Item, bool);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project()
+            .inner
+            .poll_next(cx)
+            .map(|item| item.map(|item| (item, true)))
+    }
+}
+
+impl<S> Stream for PassthroughFlagged<S, S::Item>
+where
+    S: Stream,
+{
+    type Item = (S::Item, bool);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        let current = match this.ahead.take() {
+            Some(current) => current,
+            None if *this.inner_done => return Poll::Ready(None),
+            None => match ready!(this.inner.as_mut().poll_next(cx)) {
+                Some(current) => current,
+                None => {
+                    *this.inner_done = true;
+                    return Poll::Ready(None);
+                }
+            },
+        };
+
+        if *this.inner_done {
+            return Poll::Ready(Some((current, true)));
+        }
+
+        match this.inner.as_mut().poll_next(cx) {
+            Poll::Pending => Poll::Ready(Some((current, true))),
+            Poll::Ready(None) => {
+                *this.inner_done = true;
+                Poll::Ready(Some((current, true)))
+            }
+            Poll::Ready(Some(next)) => {
+                *this.ahead = Some(next);
+                Poll::Ready(Some((current, false)))
+            }
+        }
+    }
+}
+
+impl<S> LatestFlaggedStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use crate::test_utils::ready_after_n_polls;
+
+    use super::*;
+
+    fn bursts() -> impl Stream<Item = i32> {
+        stream::iter([[1, 2, 3], [4, 5, 6], [7, 8, 9]])
+            .map(stream::iter)
+            .then(|chunk| ready_after_n_polls(chunk, 1))
+            .flatten()
+    }
+
+    #[tokio::test]
+    async fn latest_ready_items_are_always_latest() {
+        assert_eq!(
+            bursts().latest_ready_flagged().collect::<Vec<_>>().await,
+            vec![(3, true), (6, true)]
+        );
+    }
+
+    #[tokio::test]
+    async fn passthrough_empty_stream() {
+        assert!(stream::empty::<()>()
+            .passthrough_flagged()
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn all_but_the_last_burst_item_are_stale() {
+        assert_eq!(
+            bursts().passthrough_flagged().collect::<Vec<_>>().await,
+            vec![
+                (1, false),
+                (2, false),
+                (3, true),
+                (4, false),
+                (5, false),
+                (6, true),
+                (7, false),
+                (8, false),
+                (9, true),
+            ]
+        );
+    }
+}
@@ -37,12 +37,60 @@ impl<S> LatestReady<S> {
     pub fn new(inner: S) -> Self {
         Self { inner }
     }
+
+    /// Acquires a reference to the underlying stream.
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// Acquires a mutable reference to the underlying stream.
+    ///
+    /// Polling the underlying stream directly may confuse this adapter.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.inner
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream.
+    ///
+    /// Polling the underlying stream directly may confuse this adapter.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut S> {
+        self.project().inner
+    }
+
+    /// Consumes this adapter, returning the underlying stream.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
 }
 
 impl<S> TryLatestReady<S> {
     pub fn new(inner: S) -> Self {
         Self { inner }
     }
+
+    /// Acquires a reference to the underlying stream.
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// Acquires a mutable reference to the underlying stream.
+    ///
+    /// Polling the underlying stream directly may confuse this adapter.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.inner
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream.
+    ///
+    /// Polling the underlying stream directly may confuse this adapter.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut S> {
+        self.project().inner
+    }
+
+    /// Consumes this adapter, returning the underlying stream.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
 }
 
 impl<S> Stream for LatestReady<S>
@@ -62,6 +110,10 @@ where
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
 }
 
 impl<S> Stream for TryLatestReady<S>
@@ -81,6 +133,10 @@ where
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
 }
 
 impl<S> LatestReadyStreamExt for S where S: Stream + Sized {}
@@ -181,4 +237,40 @@ mod tests {
             vec![Err(()),]
         );
     }
+
+    #[tokio::test]
+    async fn accessors_reach_the_underlying_stream() {
+        let mut latest = stream::iter([1, 2, 3]).latest_ready();
+        assert_eq!(latest.get_ref().size_hint(), (3, Some(3)));
+        assert_eq!(latest.get_mut().next().await, Some(1));
+        assert_eq!(Pin::new(&mut latest).get_pin_mut().next().await, Some(2));
+        assert_eq!(latest.into_inner().collect::<Vec<_>>().await, vec![3]);
+
+        let mut try_latest = stream::iter([Ok::<_, ()>(1), Ok(2)]).try_latest_ready();
+        assert_eq!(try_latest.get_ref().size_hint(), (2, Some(2)));
+        assert_eq!(try_latest.get_mut().next().await, Some(Ok(1)));
+        assert_eq!(
+            try_latest.into_inner().collect::<Vec<_>>().await,
+            vec![Ok(2)]
+        );
+    }
+
+    #[test]
+    fn size_hint_has_no_lower_bound_and_keeps_the_upper_one() {
+        assert_eq!(
+            stream::iter([1, 2, 3]).latest_ready().size_hint(),
+            (0, Some(3))
+        );
+        assert_eq!(
+            stream::iter([Ok::<_, ()>(1), Ok(2), Ok(3)])
+                .try_latest_ready()
+                .size_hint(),
+            (0, Some(3))
+        );
+    }
+
+    #[test]
+    fn size_hint_of_an_unbounded_upstream() {
+        assert_eq!(stream::repeat(1).latest_ready().size_hint(), (0, None));
+    }
 }
@@ -0,0 +1,122 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait LeakyIntegrateStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: Into<f64>,
+{
+    /// Smooth the values with a leaky integrator: `state = state + decay * (input - state)` on each item.
+    ///
+    /// The first item initializes the state, and the state is emitted after each update.
+    /// `decay == 1.0` passes the input through unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `decay` is not within `(0.0, 1.0]`.
+    fn leaky_integrate(self, decay: f64) -> LeakyIntegrate<Self> {
+        LeakyIntegrate::new(self, decay)
+    }
+}
+
+/// Stream for [`leaky_integrate`](`LeakyIntegrateStreamExt::leaky_integrate`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct LeakyIntegrate<Stream> {
+    #[pin]
+    inner: Stream,
+    decay: f64,
+
+    state: Option<f64>,
+}
+
+impl<S> LeakyIntegrate<S> {
+    pub fn new(inner: S, decay: f64) -> Self {
+        assert!(
+            decay > 0.0 && decay <= 1.0,
+            "the decay must be within (0.0, 1.0]"
+        );
+        Self {
+            inner,
+            decay,
+            state: None,
+        }
+    }
+}
+
+impl<S> Stream for LeakyIntegrate<S>
+where
+    S: Stream,
+    S::Item: Into<f64>,
+{
+    type Item = f64;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        let Some(x) = ready!(this.inner.poll_next(cx)) else {
+            return Poll::Ready(None);
+        };
+        let x = x.into();
+
+        let state = match *this.state {
+            None => x,
+            Some(state) => state + *this.decay * (x - state),
+        };
+        *this.state = Some(state);
+
+        Poll::Ready(Some(state))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S> LeakyIntegrateStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Into<f64>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn step_input_is_approached_exponentially() {
+        assert_eq!(
+            stream::iter([0.0, 16.0, 16.0, 16.0, 16.0])
+                .leaky_integrate(0.5)
+                .collect::<Vec<_>>()
+                .await,
+            vec![0.0, 8.0, 12.0, 14.0, 15.0]
+        );
+    }
+
+    #[tokio::test]
+    async fn full_decay_passes_the_input_through() {
+        assert_eq!(
+            stream::iter([3u8, 7, 1])
+                .leaky_integrate(1.0)
+                .collect::<Vec<_>>()
+                .await,
+            vec![3.0, 7.0, 1.0]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_decay_panics() {
+        let _ = stream::empty::<f64>().leaky_integrate(0.0);
+    }
+}
@@ -1,8 +1,1204 @@
+use std::{
+    future::Future,
+    hash::Hash,
+    ops::{Add, ControlFlow, Sub},
+};
+
+use futures::{future::Either, Sink, Stream, TryStream};
+use num_traits::{CheckedAdd, CheckedSub};
+use smallvec::SmallVec;
+
 pub mod prelude;
 
+pub mod accumulate;
+pub mod arrival_delta_polls;
+#[cfg(feature = "tokio")]
+pub mod block_on_iter;
+pub mod buffer_until;
+pub mod burst_id;
+pub mod cap_per_window;
+pub mod chunks_strided;
+pub mod collect_array;
+pub mod combine_latest;
+pub mod combine_latest_fresh;
+pub mod debounce;
+pub mod debounced_distinct_polls;
+pub mod dedup;
+pub mod dedup_consecutive_errors;
+pub mod dedup_recent;
+pub mod demux;
+pub mod derivatives;
+pub mod detect_gaps;
+pub mod drop_out_of_order;
+pub mod ensure_alternating;
 pub mod expand;
+pub mod filter_window;
+pub mod fir_filter;
+pub mod first_ok;
+pub mod flat_map_iter;
+pub mod fold_to_one;
+pub mod forward_latest;
+pub mod fsm;
+pub mod group_consecutive_capped;
+pub mod hold;
+pub mod hysteresis;
+#[cfg(feature = "test-support")]
+pub mod inject_errors;
+pub mod integrate;
+pub mod interleave_snapshots;
+#[cfg(feature = "tokio")]
+pub mod into_mpsc_latest;
+pub mod join_within;
+pub mod latest_flagged;
 pub mod latest_ready;
+pub mod leaky_integrate;
+pub mod map_if;
+pub mod map_or_recover;
+pub mod map_while_inclusive;
+pub mod map_with_context;
+pub mod merge_by_timestamp;
+pub mod nth_of_burst;
+pub mod or_else_default;
+pub mod permit_gated;
+pub mod poll_retry;
+pub mod poll_weighted_average;
+pub mod ratchet;
+pub mod ready_chunks_biased;
+pub mod reduce_chunks;
+pub mod reduce_until;
+pub mod repeat_each;
+pub mod resample_latest;
+pub mod ring;
+pub mod rolling_correlation;
+pub mod rolling_sum_checked;
+pub mod rolling_zscore;
+pub mod running_extremes;
+pub mod running_percentile;
+pub mod running_stats;
+pub mod sample;
+pub mod sessionize_polls;
+pub mod settle_within;
+pub mod skip_first;
+pub mod snapshot_on;
+pub mod split_at_first;
+pub mod split_by_threshold;
+pub mod stable_burst;
+pub mod stamp_with;
+pub mod stateful_by_key_lru;
+pub mod throttle;
+#[cfg(feature = "time")]
+pub mod throttle_counted;
+pub mod with_high_water;
+pub mod with_latest_from;
+pub mod with_periodic_aggregate;
+pub mod with_recovery_depth;
+pub mod with_termination_marker;
 pub mod zip_biased;
+pub mod zip_indexed;
 
+mod drain;
+mod sync_utils;
 #[cfg(test)]
 mod test_utils;
+
+/// All the combinators for infallible streams, forwarding to the per-combinator extension traits.
+///
+/// An alternative to [`prelude`] for a single import. It is not meant to be imported alongside the prelude
+/// or the individual traits: the method calls would be ambiguous.
+pub trait StreamsExt: Stream + Sized {
+    /// See [`accumulate_vec`](accumulate::AccumulateStreamExt::accumulate_vec).
+    fn accumulate_vec(self) -> accumulate::AccumulateVec<Self, Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        accumulate::AccumulateStreamExt::accumulate_vec(self)
+    }
+
+    /// See [`accumulate_shared`](accumulate::AccumulateStreamExt::accumulate_shared).
+    fn accumulate_shared(self) -> accumulate::AccumulateShared<Self, Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        accumulate::AccumulateStreamExt::accumulate_shared(self)
+    }
+
+    /// See [`arrival_delta_polls`](arrival_delta_polls::ArrivalDeltaPollsStreamExt::arrival_delta_polls).
+    fn arrival_delta_polls(self) -> arrival_delta_polls::ArrivalDeltaPolls<Self> {
+        arrival_delta_polls::ArrivalDeltaPollsStreamExt::arrival_delta_polls(self)
+    }
+
+    /// See [`block_on_iter`](block_on_iter::BlockOnIterStreamExt::block_on_iter).
+    #[cfg(feature = "tokio")]
+    fn block_on_iter(self, handle: tokio::runtime::Handle) -> block_on_iter::BlockOnIter<Self> {
+        block_on_iter::BlockOnIterStreamExt::block_on_iter(self, handle)
+    }
+
+    /// See [`buffer_until`](buffer_until::BufferUntilStreamExt::buffer_until).
+    fn buffer_until<F>(self, f: F) -> buffer_until::BufferUntil<Self, F, Self::Item>
+    where
+        F: FnMut(&[Self::Item]) -> bool,
+    {
+        buffer_until::BufferUntilStreamExt::buffer_until(self, f)
+    }
+
+    /// See [`burst_id`](burst_id::BurstIdStreamExt::burst_id).
+    fn burst_id(self) -> burst_id::BurstId<Self> {
+        burst_id::BurstIdStreamExt::burst_id(self)
+    }
+
+    /// See [`cap_per_window`](cap_per_window::CapPerWindowStreamExt::cap_per_window).
+    fn cap_per_window(
+        self,
+        max: usize,
+        window: usize,
+    ) -> cap_per_window::CapPerWindow<Self, Self::Item> {
+        cap_per_window::CapPerWindowStreamExt::cap_per_window(self, max, window)
+    }
+
+    /// See [`chunks_strided`](chunks_strided::ChunksStridedStreamExt::chunks_strided).
+    fn chunks_strided(
+        self,
+        size: usize,
+        step: usize,
+        partial: chunks_strided::PartialWindow,
+    ) -> chunks_strided::ChunksStrided<Self, Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        chunks_strided::ChunksStridedStreamExt::chunks_strided(self, size, step, partial)
+    }
+
+    /// See [`collect_array`](collect_array::CollectArrayStreamExt::collect_array).
+    fn collect_array<const N: usize>(self) -> collect_array::CollectArray<Self, Self::Item, N> {
+        collect_array::CollectArrayStreamExt::collect_array(self)
+    }
+
+    /// See [`combine_latest`](combine_latest::CombineLatestStreamExt::combine_latest).
+    fn combine_latest<R>(
+        self,
+        right: R,
+    ) -> combine_latest::CombineLatest<Self, R, Self::Item, R::Item>
+    where
+        R: Stream,
+        Self::Item: Clone,
+        R::Item: Clone,
+    {
+        combine_latest::CombineLatestStreamExt::combine_latest(self, right)
+    }
+
+    /// See [`combine_latest_fresh`](combine_latest_fresh::CombineLatestFreshStreamExt::combine_latest_fresh).
+    fn combine_latest_fresh<R>(
+        self,
+        right: R,
+        max_lag_polls: usize,
+    ) -> combine_latest_fresh::CombineLatestFresh<Self, R, Self::Item, R::Item>
+    where
+        R: Stream,
+        Self::Item: Clone,
+        R::Item: Clone,
+    {
+        combine_latest_fresh::CombineLatestFreshStreamExt::combine_latest_fresh(
+            self,
+            right,
+            max_lag_polls,
+        )
+    }
+
+    /// See [`debounce`](debounce::DebounceStreamExt::debounce).
+    fn debounce<F, D>(self, delay: F) -> debounce::Debounce<Self, F, D, Self::Item>
+    where
+        F: FnMut() -> D,
+        D: Future,
+    {
+        debounce::DebounceStreamExt::debounce(self, delay)
+    }
+
+    /// See [`debounced_distinct_polls`](debounced_distinct_polls::DebouncedDistinctPollsStreamExt::debounced_distinct_polls).
+    fn debounced_distinct_polls<F, Key>(
+        self,
+        quiet: usize,
+        key: F,
+    ) -> debounced_distinct_polls::DebouncedDistinctPolls<Self, F, Self::Item, Key>
+    where
+        F: FnMut(&Self::Item) -> Key,
+        Key: PartialEq,
+    {
+        debounced_distinct_polls::DebouncedDistinctPollsStreamExt::debounced_distinct_polls(
+            self, quiet, key,
+        )
+    }
+
+    /// See [`dedup`](dedup::DedupStreamExt::dedup).
+    fn dedup(self) -> dedup::Dedup<Self, Self::Item>
+    where
+        Self::Item: PartialEq + Clone,
+    {
+        dedup::DedupStreamExt::dedup(self)
+    }
+
+    /// See [`dedup_by_key`](dedup::DedupStreamExt::dedup_by_key).
+    fn dedup_by_key<K, F>(self, key_fn: F) -> dedup::DedupByKey<Self, F, K>
+    where
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq + Clone,
+    {
+        dedup::DedupStreamExt::dedup_by_key(self, key_fn)
+    }
+
+    /// See [`dedup_recent`](dedup_recent::DedupRecentStreamExt::dedup_recent).
+    fn dedup_recent<F, K>(self, n: usize, key: F) -> dedup_recent::DedupRecent<Self, F, K>
+    where
+        F: FnMut(&Self::Item) -> K,
+        K: Eq + Hash + Clone,
+    {
+        dedup_recent::DedupRecentStreamExt::dedup_recent(self, n, key)
+    }
+
+    /// See [`demux`](demux::DemuxStreamExt::demux).
+    fn demux<K, F>(self, key: F) -> demux::Demux<Self, K, F>
+    where
+        F: FnMut(&Self::Item) -> K,
+        K: Eq + Hash + Clone,
+    {
+        demux::DemuxStreamExt::demux(self, key)
+    }
+
+    /// See [`derivatives`](derivatives::DerivativesStreamExt::derivatives).
+    fn derivatives(self) -> derivatives::Derivatives<Self, Self::Item>
+    where
+        Self::Item: Sub<Output = Self::Item> + Clone,
+    {
+        derivatives::DerivativesStreamExt::derivatives(self)
+    }
+
+    /// See [`detect_gaps`](detect_gaps::DetectGapsStreamExt::detect_gaps).
+    fn detect_gaps<F>(self, seq_of: F) -> detect_gaps::DetectGaps<Self, Self::Item, F>
+    where
+        F: FnMut(&Self::Item) -> u64,
+    {
+        detect_gaps::DetectGapsStreamExt::detect_gaps(self, seq_of)
+    }
+
+    /// See [`drop_out_of_order`](drop_out_of_order::DropOutOfOrderStreamExt::drop_out_of_order).
+    fn drop_out_of_order<F>(self, seq_of: F) -> drop_out_of_order::DropOutOfOrder<Self, F>
+    where
+        F: FnMut(&Self::Item) -> u64,
+    {
+        drop_out_of_order::DropOutOfOrderStreamExt::drop_out_of_order(self, seq_of)
+    }
+
+    /// See [`ensure_alternating`](ensure_alternating::EnsureAlternatingStreamExt::ensure_alternating).
+    fn ensure_alternating<F>(self, category: F) -> ensure_alternating::EnsureAlternating<Self, F>
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        ensure_alternating::EnsureAlternatingStreamExt::ensure_alternating(self, category)
+    }
+
+    /// See [`expand`](expand::ExpandStreamExt::expand).
+    fn expand(self) -> expand::Expand<Self, Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        expand::ExpandStreamExt::expand(self)
+    }
+
+    /// See [`latest_or_repeat`](expand::ExpandStreamExt::latest_or_repeat).
+    fn latest_or_repeat(self) -> expand::Expand<Self, Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        expand::ExpandStreamExt::latest_or_repeat(self)
+    }
+
+    /// See [`expand_with_freshness`](expand::ExpandStreamExt::expand_with_freshness).
+    fn expand_with_freshness(
+        self,
+    ) -> (
+        expand::ExpandFresh<Self, Self::Item>,
+        expand::FreshnessHandle,
+    )
+    where
+        Self::Item: Clone,
+    {
+        expand::ExpandStreamExt::expand_with_freshness(self)
+    }
+
+    /// See [`expand_n`](expand::ExpandStreamExt::expand_n).
+    fn expand_n(self, max_repeats: usize) -> expand::ExpandN<Self, Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        expand::ExpandStreamExt::expand_n(self, max_repeats)
+    }
+
+    /// See [`expand_while`](expand::ExpandStreamExt::expand_while).
+    fn expand_while<P>(self, pred: P) -> expand::ExpandWhile<Self, P, Self::Item>
+    where
+        P: FnMut(&Self::Item) -> bool,
+        Self::Item: Clone,
+    {
+        expand::ExpandStreamExt::expand_while(self, pred)
+    }
+
+    /// See [`expand_yielding`](expand::ExpandStreamExt::expand_yielding).
+    #[cfg(feature = "tokio")]
+    fn expand_yielding(self) -> expand::ExpandYielding<Self, Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        expand::ExpandStreamExt::expand_yielding(self)
+    }
+
+    /// See [`filter_window`](filter_window::FilterWindowStreamExt::filter_window).
+    fn filter_window<F>(self, n: usize, f: F) -> filter_window::FilterWindow<Self, Self::Item, F>
+    where
+        F: FnMut(&[Self::Item], &Self::Item) -> bool,
+        Self::Item: Clone,
+    {
+        filter_window::FilterWindowStreamExt::filter_window(self, n, f)
+    }
+
+    /// See [`fir_filter`](fir_filter::FirFilterStreamExt::fir_filter).
+    fn fir_filter(self, kernel: Vec<f64>, normalize: bool) -> fir_filter::FirFilter<Self>
+    where
+        Self::Item: Into<f64>,
+    {
+        fir_filter::FirFilterStreamExt::fir_filter(self, kernel, normalize)
+    }
+
+    /// See [`flat_map_iter`](flat_map_iter::FlatMapIterStreamExt::flat_map_iter).
+    fn flat_map_iter<F, I>(self, f: F) -> flat_map_iter::FlatMapIter<Self, F, I::IntoIter>
+    where
+        F: FnMut(Self::Item) -> I,
+        I: IntoIterator,
+    {
+        flat_map_iter::FlatMapIterStreamExt::flat_map_iter(self, f)
+    }
+
+    /// See [`fold_to_one`](fold_to_one::FoldToOneStreamExt::fold_to_one).
+    fn fold_to_one<A, F>(self, init: A, f: F) -> fold_to_one::FoldToOne<Self, F, A>
+    where
+        F: FnMut(A, Self::Item) -> A,
+    {
+        fold_to_one::FoldToOneStreamExt::fold_to_one(self, init, f)
+    }
+
+    /// See [`forward_latest`](forward_latest::ForwardLatestStreamExt::forward_latest).
+    fn forward_latest<Si>(self, sink: Si) -> forward_latest::ForwardLatest<Self, Si, Self::Item>
+    where
+        Si: Sink<Self::Item>,
+    {
+        forward_latest::ForwardLatestStreamExt::forward_latest(self, sink)
+    }
+
+    /// See [`fsm`](fsm::FsmStreamExt::fsm).
+    fn fsm<St, F, T>(self, init: St, f: F) -> fsm::Fsm<Self, St, F, T>
+    where
+        F: FnMut(&mut St, Self::Item) -> SmallVec<[T; 1]>,
+    {
+        fsm::FsmStreamExt::fsm(self, init, f)
+    }
+
+    /// See [`group_consecutive_capped`](group_consecutive_capped::GroupConsecutiveCappedStreamExt::group_consecutive_capped).
+    fn group_consecutive_capped<K, F>(
+        self,
+        f: F,
+        max: usize,
+    ) -> group_consecutive_capped::GroupConsecutiveCapped<Self, F, K, Self::Item>
+    where
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq,
+    {
+        group_consecutive_capped::GroupConsecutiveCappedStreamExt::group_consecutive_capped(
+            self, f, max,
+        )
+    }
+
+    /// See [`hold`](hold::HoldStreamExt::hold).
+    fn hold(self, initial: Self::Item) -> hold::Hold<Self, Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        hold::HoldStreamExt::hold(self, initial)
+    }
+
+    /// See [`hysteresis`](hysteresis::HysteresisStreamExt::hysteresis).
+    fn hysteresis(self, band: Self::Item) -> hysteresis::Hysteresis<Self, Self::Item>
+    where
+        Self::Item: PartialOrd + Sub<Output = Self::Item> + Clone,
+    {
+        hysteresis::HysteresisStreamExt::hysteresis(self, band)
+    }
+
+    /// See [`integrate`](integrate::IntegrateStreamExt::integrate).
+    fn integrate(self, base: Self::Item) -> integrate::Integrate<Self, Self::Item>
+    where
+        Self::Item: Add<Output = Self::Item> + Clone,
+    {
+        integrate::IntegrateStreamExt::integrate(self, base)
+    }
+
+    /// See [`interleave_snapshots`](interleave_snapshots::InterleaveSnapshotsStreamExt::interleave_snapshots).
+    fn interleave_snapshots<F, S>(
+        self,
+        every: usize,
+        snapshot: F,
+    ) -> interleave_snapshots::InterleaveSnapshots<Self, F>
+    where
+        F: FnMut() -> S,
+    {
+        interleave_snapshots::InterleaveSnapshotsStreamExt::interleave_snapshots(
+            self, every, snapshot,
+        )
+    }
+
+    /// See [`into_mpsc_latest`](into_mpsc_latest::IntoMpscLatestStreamExt::into_mpsc_latest).
+    #[cfg(feature = "tokio")]
+    fn into_mpsc_latest(
+        self,
+        tx: tokio::sync::mpsc::Sender<Self::Item>,
+    ) -> into_mpsc_latest::IntoMpscLatest<Self, Self::Item>
+    where
+        Self::Item: Send + 'static,
+    {
+        into_mpsc_latest::IntoMpscLatestStreamExt::into_mpsc_latest(self, tx)
+    }
+
+    /// See [`join_within`](join_within::JoinWithinStreamExt::join_within).
+    fn join_within<R, F, G>(
+        self,
+        right: R,
+        tolerance: u64,
+        left_ts: F,
+        right_ts: G,
+    ) -> join_within::JoinWithin<Self, R, F, G, Self::Item, R::Item>
+    where
+        R: Stream,
+        F: FnMut(&Self::Item) -> u64,
+        G: FnMut(&R::Item) -> u64,
+    {
+        join_within::JoinWithinStreamExt::join_within(self, right, tolerance, left_ts, right_ts)
+    }
+
+    /// See [`latest_ready_flagged`](latest_flagged::LatestFlaggedStreamExt::latest_ready_flagged).
+    fn latest_ready_flagged(self) -> latest_flagged::LatestReadyFlagged<Self> {
+        latest_flagged::LatestFlaggedStreamExt::latest_ready_flagged(self)
+    }
+
+    /// See [`passthrough_flagged`](latest_flagged::LatestFlaggedStreamExt::passthrough_flagged).
+    fn passthrough_flagged(self) -> latest_flagged::PassthroughFlagged<Self, Self::Item> {
+        latest_flagged::LatestFlaggedStreamExt::passthrough_flagged(self)
+    }
+
+    /// See [`latest_ready`](latest_ready::LatestReadyStreamExt::latest_ready).
+    fn latest_ready(self) -> latest_ready::LatestReady<Self> {
+        latest_ready::LatestReadyStreamExt::latest_ready(self)
+    }
+
+    /// See [`leaky_integrate`](leaky_integrate::LeakyIntegrateStreamExt::leaky_integrate).
+    fn leaky_integrate(self, decay: f64) -> leaky_integrate::LeakyIntegrate<Self>
+    where
+        Self::Item: Into<f64>,
+    {
+        leaky_integrate::LeakyIntegrateStreamExt::leaky_integrate(self, decay)
+    }
+
+    /// See [`map_if`](map_if::MapIfStreamExt::map_if).
+    fn map_if<P, F>(self, pred: P, f: F) -> map_if::MapIf<Self, P, F>
+    where
+        P: FnMut(&Self::Item) -> bool,
+        F: FnMut(Self::Item) -> Self::Item,
+    {
+        map_if::MapIfStreamExt::map_if(self, pred, f)
+    }
+
+    /// See [`map_or_recover`](map_or_recover::MapOrRecoverStreamExt::map_or_recover).
+    fn map_or_recover<F, G, T, E>(
+        self,
+        f: F,
+        recover: G,
+    ) -> map_or_recover::MapOrRecover<Self, F, G>
+    where
+        F: FnMut(&Self::Item) -> Result<T, E>,
+        G: FnMut(Self::Item, E) -> T,
+    {
+        map_or_recover::MapOrRecoverStreamExt::map_or_recover(self, f, recover)
+    }
+
+    /// See [`map_while_inclusive`](map_while_inclusive::MapWhileInclusiveStreamExt::map_while_inclusive).
+    fn map_while_inclusive<F, T>(self, f: F) -> map_while_inclusive::MapWhileInclusive<Self, F>
+    where
+        F: FnMut(Self::Item) -> ControlFlow<T, T>,
+    {
+        map_while_inclusive::MapWhileInclusiveStreamExt::map_while_inclusive(self, f)
+    }
+
+    /// See [`map_with_context`](map_with_context::MapWithContextStreamExt::map_with_context).
+    fn map_with_context<F, T>(self, f: F) -> map_with_context::MapWithContext<Self, F, Self::Item>
+    where
+        F: FnMut(usize, Option<&Self::Item>, Self::Item) -> T,
+        Self::Item: Clone,
+    {
+        map_with_context::MapWithContextStreamExt::map_with_context(self, f)
+    }
+
+    /// See [`merge_by_timestamp`](merge_by_timestamp::MergeByTimestampStreamExt::merge_by_timestamp).
+    fn merge_by_timestamp<R, F>(
+        self,
+        right: R,
+        ts: F,
+    ) -> merge_by_timestamp::MergeByTimestamp<Self, R, F, Self::Item>
+    where
+        R: Stream<Item = Self::Item>,
+        F: FnMut(&Self::Item) -> u64,
+    {
+        merge_by_timestamp::MergeByTimestampStreamExt::merge_by_timestamp(self, right, ts)
+    }
+
+    /// See [`nth_of_burst`](nth_of_burst::NthOfBurstStreamExt::nth_of_burst).
+    fn nth_of_burst(self, index: usize) -> nth_of_burst::NthOfBurst<Self> {
+        nth_of_burst::NthOfBurstStreamExt::nth_of_burst(self, index)
+    }
+
+    /// See [`or_else_default`](or_else_default::OrElseDefaultStreamExt::or_else_default).
+    fn or_else_default(
+        self,
+        default: Self::Item,
+    ) -> or_else_default::OrElseDefault<Self, Self::Item> {
+        or_else_default::OrElseDefaultStreamExt::or_else_default(self, default)
+    }
+
+    /// See [`permit_gated`](permit_gated::PermitGatedStreamExt::permit_gated).
+    fn permit_gated<P>(
+        self,
+        permits: P,
+        buffering: permit_gated::GateBuffering,
+    ) -> permit_gated::PermitGated<Self, P, Self::Item>
+    where
+        P: Stream,
+    {
+        permit_gated::PermitGatedStreamExt::permit_gated(self, permits, buffering)
+    }
+
+    /// See [`poll_retry`](poll_retry::PollRetryStreamExt::poll_retry).
+    fn poll_retry(self, k: usize) -> poll_retry::PollRetry<Self> {
+        poll_retry::PollRetryStreamExt::poll_retry(self, k)
+    }
+
+    /// See [`poll_weighted_average`](poll_weighted_average::PollWeightedAverageStreamExt::poll_weighted_average).
+    fn poll_weighted_average(
+        self,
+        window: usize,
+    ) -> poll_weighted_average::PollWeightedAverage<Self>
+    where
+        Self::Item: Into<f64>,
+    {
+        poll_weighted_average::PollWeightedAverageStreamExt::poll_weighted_average(self, window)
+    }
+
+    /// See [`ratchet`](ratchet::RatchetStreamExt::ratchet).
+    fn ratchet(self) -> ratchet::Ratchet<Self, Self::Item>
+    where
+        Self::Item: PartialOrd + Clone,
+    {
+        ratchet::RatchetStreamExt::ratchet(self)
+    }
+
+    /// See [`ready_chunks_biased`](ready_chunks_biased::ReadyChunksBiasedStreamExt::ready_chunks_biased).
+    fn ready_chunks_biased(self, max_len: usize) -> ready_chunks_biased::ReadyChunksBiased<Self> {
+        ready_chunks_biased::ReadyChunksBiasedStreamExt::ready_chunks_biased(self, max_len)
+    }
+
+    /// See [`reduce_chunks`](reduce_chunks::ReduceChunksStreamExt::reduce_chunks).
+    fn reduce_chunks<F>(self, n: usize, f: F) -> reduce_chunks::ReduceChunks<Self, F, Self::Item>
+    where
+        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+    {
+        reduce_chunks::ReduceChunksStreamExt::reduce_chunks(self, n, f)
+    }
+
+    /// See [`reduce_until`](reduce_until::ReduceUntilStreamExt::reduce_until).
+    fn reduce_until<A, F, P>(
+        self,
+        init: A,
+        f: F,
+        done: P,
+    ) -> reduce_until::ReduceUntil<Self, A, F, P>
+    where
+        F: FnMut(A, Self::Item) -> A,
+        P: FnMut(&A) -> bool,
+    {
+        reduce_until::ReduceUntilStreamExt::reduce_until(self, init, f, done)
+    }
+
+    /// See [`repeat_each`](repeat_each::RepeatEachStreamExt::repeat_each).
+    fn repeat_each(self, k: usize) -> repeat_each::RepeatEach<Self, Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        repeat_each::RepeatEachStreamExt::repeat_each(self, k)
+    }
+
+    /// See [`resample_latest`](resample_latest::ResampleLatestStreamExt::resample_latest).
+    fn resample_latest(self) -> resample_latest::ResampleLatest<Self, Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        resample_latest::ResampleLatestStreamExt::resample_latest(self)
+    }
+
+    /// See [`ring`](ring::RingStreamExt::ring).
+    fn ring(self, n: usize) -> (ring::Ring<Self>, ring::RingHandle<Self::Item>)
+    where
+        Self::Item: Clone,
+    {
+        ring::RingStreamExt::ring(self, n)
+    }
+
+    /// See [`rolling_correlation`](rolling_correlation::RollingCorrelationStreamExt::rolling_correlation).
+    fn rolling_correlation<R>(
+        self,
+        right: R,
+        n: usize,
+    ) -> rolling_correlation::RollingCorrelation<Self, R, Self::Item>
+    where
+        R: Stream,
+        R::Item: Into<f64>,
+        Self::Item: Into<f64>,
+    {
+        rolling_correlation::RollingCorrelationStreamExt::rolling_correlation(self, right, n)
+    }
+
+    /// See [`rolling_sum_checked`](rolling_sum_checked::RollingSumCheckedStreamExt::rolling_sum_checked).
+    fn rolling_sum_checked(
+        self,
+        n: usize,
+    ) -> rolling_sum_checked::RollingSumChecked<Self, Self::Item>
+    where
+        Self::Item: CheckedAdd + CheckedSub + Clone,
+    {
+        rolling_sum_checked::RollingSumCheckedStreamExt::rolling_sum_checked(self, n)
+    }
+
+    /// See [`rolling_zscore`](rolling_zscore::RollingZscoreStreamExt::rolling_zscore).
+    fn rolling_zscore(self, n: usize) -> rolling_zscore::RollingZscore<Self>
+    where
+        Self::Item: Into<f64>,
+    {
+        rolling_zscore::RollingZscoreStreamExt::rolling_zscore(self, n)
+    }
+
+    /// See [`running_extremes`](running_extremes::RunningExtremesStreamExt::running_extremes).
+    fn running_extremes(self) -> running_extremes::RunningExtremes<Self, Self::Item>
+    where
+        Self::Item: Ord + Clone,
+    {
+        running_extremes::RunningExtremesStreamExt::running_extremes(self)
+    }
+
+    /// See [`running_percentile`](running_percentile::RunningPercentileStreamExt::running_percentile).
+    fn running_percentile(
+        self,
+        p: f64,
+        window: usize,
+    ) -> running_percentile::RunningPercentile<Self>
+    where
+        Self::Item: Into<f64>,
+    {
+        running_percentile::RunningPercentileStreamExt::running_percentile(self, p, window)
+    }
+
+    /// See [`running_stats`](running_stats::RunningStatsStreamExt::running_stats).
+    fn running_stats(self) -> running_stats::RunningStats<Self>
+    where
+        Self::Item: Into<f64>,
+    {
+        running_stats::RunningStatsStreamExt::running_stats(self)
+    }
+
+    /// See [`sample`](sample::SampleStreamExt::sample).
+    fn sample<T>(self, trigger: T) -> sample::Sample<Self, T, Self::Item>
+    where
+        T: Stream,
+        Self::Item: Clone,
+    {
+        sample::SampleStreamExt::sample(self, trigger)
+    }
+
+    /// See [`sessionize_polls`](sessionize_polls::SessionizePollsStreamExt::sessionize_polls).
+    fn sessionize_polls(self, gap: usize) -> sessionize_polls::SessionizePolls<Self, Self::Item> {
+        sessionize_polls::SessionizePollsStreamExt::sessionize_polls(self, gap)
+    }
+
+    /// See [`settle_within`](settle_within::SettleWithinStreamExt::settle_within).
+    fn settle_within(
+        self,
+        epsilon: Self::Item,
+        count: usize,
+        unsettled: settle_within::Unsettled,
+    ) -> settle_within::SettleWithin<Self, Self::Item>
+    where
+        Self::Item: PartialOrd + Sub<Output = Self::Item> + Clone,
+    {
+        settle_within::SettleWithinStreamExt::settle_within(self, epsilon, count, unsettled)
+    }
+
+    /// See [`skip_first`](skip_first::SkipFirstStreamExt::skip_first).
+    fn skip_first(self) -> skip_first::SkipFirst<Self> {
+        skip_first::SkipFirstStreamExt::skip_first(self)
+    }
+
+    /// See [`snapshot_on`](snapshot_on::SnapshotOnStreamExt::snapshot_on).
+    fn snapshot_on<C, F, S>(self, control: C, snapshot: F) -> snapshot_on::SnapshotOn<Self, C, F>
+    where
+        C: Stream,
+        F: FnMut() -> S,
+    {
+        snapshot_on::SnapshotOnStreamExt::snapshot_on(self, control, snapshot)
+    }
+
+    /// See [`split_at_first`](split_at_first::SplitAtFirstStreamExt::split_at_first).
+    fn split_at_first<F>(
+        self,
+        f: F,
+        delimiter: split_at_first::Delimiter,
+    ) -> (
+        split_at_first::Before<Self, F>,
+        split_at_first::After<Self, F>,
+    )
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        split_at_first::SplitAtFirstStreamExt::split_at_first(self, f, delimiter)
+    }
+
+    /// See [`split_by_threshold`](split_by_threshold::SplitByThresholdStreamExt::split_by_threshold).
+    fn split_by_threshold(
+        self,
+        level: Self::Item,
+    ) -> (
+        split_by_threshold::AboveStream<Self>,
+        split_by_threshold::BelowStream<Self>,
+    )
+    where
+        Self::Item: PartialOrd + Clone,
+    {
+        split_by_threshold::SplitByThresholdStreamExt::split_by_threshold(self, level)
+    }
+
+    /// See [`stable_burst`](stable_burst::StableBurstStreamExt::stable_burst).
+    fn stable_burst(self, epsilon: Self::Item) -> stable_burst::StableBurst<Self, Self::Item>
+    where
+        Self::Item: PartialOrd + Sub<Output = Self::Item> + Clone,
+    {
+        stable_burst::StableBurstStreamExt::stable_burst(self, epsilon)
+    }
+
+    /// See [`stamp_with`](stamp_with::StampWithStreamExt::stamp_with).
+    fn stamp_with<C>(self, clock: C) -> stamp_with::StampWith<Self, C>
+    where
+        C: Stream<Item = u64>,
+    {
+        stamp_with::StampWithStreamExt::stamp_with(self, clock)
+    }
+
+    /// See [`stateful_by_key_lru`](stateful_by_key_lru::StatefulByKeyLruStreamExt::stateful_by_key_lru).
+    fn stateful_by_key_lru<K, St, F, F2, T>(
+        self,
+        capacity: usize,
+        key: F,
+        init: St,
+        update: F2,
+    ) -> stateful_by_key_lru::StatefulByKeyLru<Self, K, St, F, F2>
+    where
+        K: Eq + Hash + Clone,
+        St: Clone,
+        F: FnMut(&Self::Item) -> K,
+        F2: FnMut(&mut St, Self::Item) -> T,
+    {
+        stateful_by_key_lru::StatefulByKeyLruStreamExt::stateful_by_key_lru(
+            self, capacity, key, init, update,
+        )
+    }
+
+    /// See [`throttle`](throttle::ThrottleStreamExt::throttle).
+    fn throttle<F, D>(
+        self,
+        edge: throttle::ThrottleEdge,
+        delay: F,
+    ) -> throttle::Throttle<Self, F, D, Self::Item>
+    where
+        F: FnMut() -> D,
+        D: Future,
+    {
+        throttle::ThrottleStreamExt::throttle(self, edge, delay)
+    }
+
+    /// See [`throttle_counted`](throttle_counted::ThrottleCountedStreamExt::throttle_counted).
+    #[cfg(feature = "time")]
+    fn throttle_counted(
+        self,
+        period: std::time::Duration,
+    ) -> throttle_counted::ThrottleCounted<Self, Self::Item> {
+        throttle_counted::ThrottleCountedStreamExt::throttle_counted(self, period)
+    }
+
+    /// See [`with_high_water`](with_high_water::WithHighWaterStreamExt::with_high_water).
+    fn with_high_water(
+        self,
+    ) -> (
+        with_high_water::WithHighWater<Self>,
+        with_high_water::HighWaterHandle,
+    )
+    where
+        Self: with_high_water::Buffered,
+    {
+        with_high_water::WithHighWaterStreamExt::with_high_water(self)
+    }
+
+    /// See [`with_latest_from`](with_latest_from::WithLatestFromStreamExt::with_latest_from).
+    fn with_latest_from<R>(self, right: R) -> with_latest_from::WithLatestFrom<Self, R, R::Item>
+    where
+        R: Stream,
+        R::Item: Clone,
+    {
+        with_latest_from::WithLatestFromStreamExt::with_latest_from(self, right)
+    }
+
+    /// See [`with_periodic_aggregate`](with_periodic_aggregate::WithPeriodicAggregateStreamExt::with_periodic_aggregate).
+    fn with_periodic_aggregate<A, F, G, Sn>(
+        self,
+        every: usize,
+        init: A,
+        fold: F,
+        emit: G,
+    ) -> with_periodic_aggregate::WithPeriodicAggregate<Self, A, F, G>
+    where
+        A: Clone,
+        F: FnMut(&mut A, &Self::Item),
+        G: FnMut(&A) -> Sn,
+    {
+        with_periodic_aggregate::WithPeriodicAggregateStreamExt::with_periodic_aggregate(
+            self, every, init, fold, emit,
+        )
+    }
+
+    /// See [`zip_biased`](zip_biased::ZipBiasedStreamExt::zip_biased).
+    fn zip_biased<R>(self, right: R) -> zip_biased::ZipBiased<Self, R, Self::Item>
+    where
+        R: Stream,
+    {
+        zip_biased::ZipBiasedStreamExt::zip_biased(self, right)
+    }
+
+    /// See [`zip3_biased`](zip_biased::ZipBiasedStreamExt::zip3_biased).
+    fn zip3_biased<B, C>(
+        self,
+        b: B,
+        c: C,
+    ) -> zip_biased::Zip3Biased<Self, B, C, Self::Item, B::Item>
+    where
+        B: Stream,
+        C: Stream,
+    {
+        zip_biased::ZipBiasedStreamExt::zip3_biased(self, b, c)
+    }
+
+    /// See [`zip_biased_all`](zip_biased::ZipBiasedStreamExt::zip_biased_all).
+    fn zip_biased_all<R>(
+        self,
+        rights: Vec<R>,
+    ) -> zip_biased::ZipBiasedAll<Self, R, Self::Item, R::Item>
+    where
+        R: Stream,
+    {
+        zip_biased::ZipBiasedStreamExt::zip_biased_all(self, rights)
+    }
+
+    /// See [`zip_biased_right`](zip_biased::ZipBiasedStreamExt::zip_biased_right).
+    fn zip_biased_right<R>(self, right: R) -> zip_biased::ZipBiasedRight<Self, R, R::Item>
+    where
+        R: Stream,
+    {
+        zip_biased::ZipBiasedStreamExt::zip_biased_right(self, right)
+    }
+
+    /// See [`zip_biased_finish`](zip_biased::ZipBiasedStreamExt::zip_biased_finish).
+    fn zip_biased_finish<R, F>(
+        self,
+        right: R,
+        finish: F,
+    ) -> zip_biased::ZipBiasedFinish<Self, R, Self::Item, F>
+    where
+        R: Stream,
+        F: FnMut(Either<Self::Item, R::Item>),
+    {
+        zip_biased::ZipBiasedStreamExt::zip_biased_finish(self, right, finish)
+    }
+
+    /// See [`zip_indexed`](zip_indexed::ZipIndexedStreamExt::zip_indexed).
+    fn zip_indexed<T>(
+        self,
+        table: Vec<T>,
+        mode: zip_indexed::IndexMode,
+    ) -> zip_indexed::ZipIndexed<Self, T>
+    where
+        T: Clone,
+    {
+        zip_indexed::ZipIndexedStreamExt::zip_indexed(self, table, mode)
+    }
+}
+
+/// All the combinators for fallible streams, similar to [`StreamsExt`] but for `TryStream`.
+pub trait TryStreamsExt: Stream + TryStream + Sized {
+    /// See [`try_dedup`](dedup::TryDedupStreamExt::try_dedup).
+    fn try_dedup(self) -> dedup::TryDedup<Self, Self::Ok>
+    where
+        Self::Ok: PartialEq + Clone,
+    {
+        dedup::TryDedupStreamExt::try_dedup(self)
+    }
+
+    /// See [`try_dedup_by_key`](dedup::TryDedupStreamExt::try_dedup_by_key).
+    fn try_dedup_by_key<K, F>(self, key_fn: F) -> dedup::TryDedupByKey<Self, F, K>
+    where
+        F: FnMut(&Self::Ok) -> K,
+        K: PartialEq + Clone,
+    {
+        dedup::TryDedupStreamExt::try_dedup_by_key(self, key_fn)
+    }
+
+    /// See [`dedup_consecutive_errors`](dedup_consecutive_errors::DedupConsecutiveErrorsStreamExt::dedup_consecutive_errors).
+    fn dedup_consecutive_errors(
+        self,
+    ) -> dedup_consecutive_errors::DedupConsecutiveErrors<Self, Self::Error>
+    where
+        Self::Error: PartialEq + Clone,
+    {
+        dedup_consecutive_errors::DedupConsecutiveErrorsStreamExt::dedup_consecutive_errors(self)
+    }
+
+    /// See [`try_expand`](expand::TryExpandStreamExt::try_expand).
+    fn try_expand(self) -> expand::TryExpand<Self, Self::Ok>
+    where
+        Self::Ok: Clone,
+    {
+        expand::TryExpandStreamExt::try_expand(self)
+    }
+
+    /// See [`first_ok`](first_ok::FirstOkStreamExt::first_ok).
+    fn first_ok(self) -> first_ok::FirstOk<Self, Self::Error> {
+        first_ok::FirstOkStreamExt::first_ok(self)
+    }
+
+    /// See [`first_ok_collect`](first_ok::FirstOkStreamExt::first_ok_collect).
+    fn first_ok_collect(self) -> first_ok::FirstOkCollect<Self, Self::Error> {
+        first_ok::FirstOkStreamExt::first_ok_collect(self)
+    }
+
+    /// See [`try_fold_to_one`](fold_to_one::TryFoldToOneStreamExt::try_fold_to_one).
+    fn try_fold_to_one<A, F>(self, init: A, f: F) -> fold_to_one::TryFoldToOne<Self, F, A>
+    where
+        F: FnMut(A, Self::Ok) -> A,
+    {
+        fold_to_one::TryFoldToOneStreamExt::try_fold_to_one(self, init, f)
+    }
+
+    /// See [`inject_errors`](inject_errors::InjectErrorsStreamExt::inject_errors).
+    #[cfg(feature = "test-support")]
+    fn inject_errors<F>(self, every: usize, make_err: F) -> inject_errors::InjectErrors<Self, F>
+    where
+        F: FnMut() -> Self::Error,
+    {
+        inject_errors::InjectErrorsStreamExt::inject_errors(self, every, make_err)
+    }
+
+    /// See [`try_latest_ready`](latest_ready::TryLatestReadyStreamExt::try_latest_ready).
+    fn try_latest_ready(self) -> latest_ready::TryLatestReady<Self> {
+        latest_ready::TryLatestReadyStreamExt::try_latest_ready(self)
+    }
+
+    /// See [`with_recovery_depth`](with_recovery_depth::WithRecoveryDepthStreamExt::with_recovery_depth).
+    fn with_recovery_depth(
+        self,
+        errors: with_recovery_depth::DepthErrors,
+    ) -> with_recovery_depth::WithRecoveryDepth<Self> {
+        with_recovery_depth::WithRecoveryDepthStreamExt::with_recovery_depth(self, errors)
+    }
+
+    /// See [`with_termination_marker`](with_termination_marker::WithTerminationMarkerStreamExt::with_termination_marker).
+    fn with_termination_marker(self) -> with_termination_marker::WithTerminationMarker<Self> {
+        with_termination_marker::WithTerminationMarkerStreamExt::with_termination_marker(self)
+    }
+
+    /// See [`try_zip_biased`](zip_biased::TryZipBiasedStreamExt::try_zip_biased).
+    fn try_zip_biased<R>(self, right: R) -> zip_biased::TryZipBiased<Self, R, Self::Ok>
+    where
+        R: Stream + TryStream<Error = Self::Error>,
+    {
+        zip_biased::TryZipBiasedStreamExt::try_zip_biased(self, right)
+    }
+}
+
+impl<S> StreamsExt for S where S: Stream + Sized {}
+
+impl<S> TryStreamsExt for S where S: Stream + TryStream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{future, sink, stream, StreamExt as _};
+
+    use super::{StreamsExt, TryStreamsExt};
+    use crate::{
+        chunks_strided::PartialWindow, permit_gated::GateBuffering, settle_within::Unsettled,
+        split_at_first::Delimiter, throttle::ThrottleEdge, with_recovery_depth::DepthErrors,
+        zip_indexed::IndexMode,
+    };
+
+    fn ints() -> stream::Iter<std::vec::IntoIter<i32>> {
+        stream::iter(vec![1, 2, 3])
+    }
+
+    fn results() -> stream::Iter<std::vec::IntoIter<Result<i32, String>>> {
+        stream::iter(vec![Ok(1), Err("e".to_owned()), Ok(2)])
+    }
+
+    #[tokio::test]
+    async fn every_combinator_is_reachable_through_streams_ext() {
+        let _ = ints().accumulate_vec();
+        let _ = ints().accumulate_shared();
+        let _ = ints().arrival_delta_polls();
+        #[cfg(feature = "tokio")]
+        let _ = ints().block_on_iter(tokio::runtime::Handle::current());
+        let _ = ints().buffer_until(|buffer| buffer.len() == 2);
+        let _ = ints().burst_id();
+        let _ = ints().cap_per_window(1, 2);
+        let _ = ints().chunks_strided(2, 1, PartialWindow::Drop);
+        let _ = ints().collect_array::<2>();
+        let _ = ints().combine_latest(ints());
+        let _ = ints().combine_latest_fresh(ints(), 1);
+        let _ = ints().debounce(|| future::ready(()));
+        let _ = ints().debounced_distinct_polls(1, |n| *n);
+        let _ = ints().dedup();
+        let _ = ints().dedup_by_key(|n| *n);
+        let _ = ints().dedup_recent(2, |n| *n);
+        let _ = ints().demux(|n| *n);
+        let _ = ints().derivatives();
+        let _ = ints().detect_gaps(|n| *n as u64);
+        let _ = ints().drop_out_of_order(|n| *n as u64);
+        let _ = ints().ensure_alternating(|n| *n > 1);
+        let _ = ints().expand();
+        let _ = ints().latest_or_repeat();
+        let _ = ints().expand_with_freshness();
+        let _ = ints().expand_n(1);
+        let _ = ints().expand_while(|n| *n > 1);
+        #[cfg(feature = "tokio")]
+        let _ = ints().expand_yielding();
+        let _ = ints().filter_window(2, |_, _| true);
+        let _ = ints().fir_filter(vec![1.0], false);
+        let _ = ints().flat_map_iter(|n| [n, n]);
+        let _ = ints().fold_to_one(0, |sum, n| sum + n);
+        drop(ints().forward_latest(sink::drain()));
+        let _ = ints().fsm((), |_, n| smallvec::smallvec![n]);
+        let _ = ints().group_consecutive_capped(|n| *n, 2);
+        let _ = ints().hold(0);
+        let _ = ints().hysteresis(1);
+        let _ = ints().integrate(0);
+        let _ = ints().interleave_snapshots(2, || 0);
+        #[cfg(feature = "tokio")]
+        drop(ints().into_mpsc_latest(tokio::sync::mpsc::channel(1).0));
+        let _ = ints().join_within(ints(), 1, |n| *n as u64, |n| *n as u64);
+        let _ = ints().latest_ready_flagged();
+        let _ = ints().passthrough_flagged();
+        let _ = ints().latest_ready();
+        let _ = ints().leaky_integrate(0.5);
+        let _ = ints().map_if(|n| *n > 1, |n| n * 2);
+        let _ = ints().map_or_recover(|n| Ok::<_, ()>(*n), |n, ()| n);
+        let _ = ints().map_while_inclusive(std::ops::ControlFlow::<i32, i32>::Continue);
+        let _ = ints().map_with_context(|index, _, n| (index, n));
+        let _ = ints().merge_by_timestamp(ints(), |n| *n as u64);
+        let _ = ints().nth_of_burst(0);
+        let _ = ints().or_else_default(0);
+        let _ = ints().permit_gated(stream::iter([()]), GateBuffering::Fifo);
+        let _ = ints().poll_retry(1);
+        let _ = ints().poll_weighted_average(2);
+        let _ = ints().ratchet();
+        let _ = ints().ready_chunks_biased(2);
+        let _ = ints().reduce_chunks(2, |a, b| a + b);
+        let _ = ints().reduce_until(0, |sum, n| sum + n, |sum| *sum > 2);
+        let _ = ints().repeat_each(2);
+        let _ = ints().resample_latest();
+        let _ = ints().ring(2);
+        let _ = ints().rolling_correlation(ints(), 2);
+        let _ = ints().rolling_sum_checked(2);
+        let _ = ints().rolling_zscore(2);
+        let _ = ints().running_extremes();
+        let _ = ints().running_percentile(0.5, 2);
+        let _ = ints().running_stats();
+        let _ = ints().sample(stream::iter([()]));
+        let _ = ints().sessionize_polls(1);
+        let _ = ints().settle_within(1, 2, Unsettled::Discard);
+        let _ = ints().skip_first();
+        let _ = ints().snapshot_on(stream::iter([()]), || 0);
+        let _ = ints().split_at_first(|n| *n > 1, Delimiter::Include);
+        let _ = ints().split_by_threshold(2);
+        let _ = ints().stable_burst(1);
+        let _ = ints().stamp_with(stream::iter([1]));
+        let _ = ints().stateful_by_key_lru(
+            2,
+            |n| *n,
+            0,
+            |count: &mut usize, _| {
+                *count += 1;
+                *count
+            },
+        );
+        let _ = ints().throttle(ThrottleEdge::Leading, || future::ready(()));
+        #[cfg(feature = "time")]
+        let _ = ints().throttle_counted(std::time::Duration::from_secs(1));
+        let _ = ints()
+            .chunks_strided(2, 1, PartialWindow::Drop)
+            .with_high_water();
+        let _ = ints().with_latest_from(ints());
+        let _ = ints().with_periodic_aggregate(2, 0, |sum, n| *sum += n, |sum| *sum);
+        let _ = ints().zip_biased(ints());
+        let _ = ints().zip3_biased(ints(), ints());
+        let _ = ints().zip_biased_all(vec![ints()]);
+        let _ = ints().zip_biased_right(ints());
+        let _ = ints().zip_biased_finish(ints(), |_| ());
+        let _ = ints().zip_indexed(vec!['a'], IndexMode::Cycle);
+    }
+
+    #[test]
+    fn every_combinator_is_reachable_through_try_streams_ext() {
+        let _ = results().try_dedup();
+        let _ = results().try_dedup_by_key(|n| *n);
+        let _ = results().dedup_consecutive_errors();
+        let _ = results().try_expand();
+        let _ = results().first_ok();
+        let _ = results().first_ok_collect();
+        let _ = results().try_fold_to_one(0, |sum, n| sum + n);
+        #[cfg(feature = "test-support")]
+        let _ = results().inject_errors(2, || "injected".to_owned());
+        let _ = results().try_latest_ready();
+        let _ = results().with_recovery_depth(DepthErrors::Emit);
+        let _ = results().with_termination_marker();
+        let _ = results().try_zip_biased(results());
+    }
+
+    #[tokio::test]
+    async fn the_umbrella_methods_forward_to_the_combinators() {
+        assert_eq!(
+            ints().zip_biased(ints()).collect::<Vec<_>>().await,
+            vec![(1, 1), (2, 2), (3, 3)]
+        );
+        assert_eq!(
+            results()
+                .try_fold_to_one(0, |sum, n| sum + n)
+                .collect::<Vec<_>>()
+                .await,
+            vec![Err("e".to_owned())]
+        );
+    }
+}
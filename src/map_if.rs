@@ -0,0 +1,91 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait MapIfStreamExt: Stream + Sized {
+    /// Apply `f` to the items matching `pred`, passing the other items through unchanged.
+    fn map_if<P, F>(self, pred: P, f: F) -> MapIf<Self, P, F>
+    where
+        P: FnMut(&Self::Item) -> bool,
+        F: FnMut(Self::Item) -> Self::Item,
+    {
+        MapIf::new(self, pred, f)
+    }
+}
+
+/// Stream for [`map_if`](`MapIfStreamExt::map_if`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct MapIf<Stream, P, F> {
+    #[pin]
+    inner: Stream,
+    pred: P,
+    f: F,
+}
+
+impl<S, P, F> MapIf<S, P, F> {
+    pub fn new(inner: S, pred: P, f: F) -> Self {
+        Self { inner, pred, f }
+    }
+}
+
+impl<S, P, F> Stream for MapIf<S, P, F>
+where
+    S: Stream,
+    P: FnMut(&S::Item) -> bool,
+    F: FnMut(S::Item) -> S::Item,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        Poll::Ready(ready!(this.inner.poll_next(cx)).map(|item| {
+            if (this.pred)(&item) {
+                (this.f)(item)
+            } else {
+                item
+            }
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S> MapIfStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn only_matching_items_are_mapped() {
+        assert_eq!(
+            stream::iter(0..5)
+                .map_if(|x| x % 2 == 0, |x| x * 2)
+                .collect::<Vec<_>>()
+                .await,
+            vec![0, 1, 4, 3, 8]
+        );
+    }
+
+    #[tokio::test]
+    async fn nothing_matches() {
+        assert_eq!(
+            stream::iter(["a", "b"])
+                .map_if(|s| s.is_empty(), |_| "empty")
+                .collect::<Vec<_>>()
+                .await,
+            vec!["a", "b"]
+        );
+    }
+}
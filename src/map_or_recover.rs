@@ -0,0 +1,98 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait MapOrRecoverStreamExt: Stream + Sized {
+    /// Map each item with the fallible `f`, turning each failure into a fallback value with `recover`.
+    ///
+    /// `recover` receives the item that `f` failed on together with the error.
+    fn map_or_recover<F, G, T, E>(self, f: F, recover: G) -> MapOrRecover<Self, F, G>
+    where
+        F: FnMut(&Self::Item) -> Result<T, E>,
+        G: FnMut(Self::Item, E) -> T,
+    {
+        MapOrRecover::new(self, f, recover)
+    }
+}
+
+/// Stream for [`map_or_recover`](`MapOrRecoverStreamExt::map_or_recover`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct MapOrRecover<Stream, F, G> {
+    #[pin]
+    inner: Stream,
+    f: F,
+    recover: G,
+}
+
+impl<S, F, G> MapOrRecover<S, F, G> {
+    pub fn new(inner: S, f: F, recover: G) -> Self {
+        Self { inner, f, recover }
+    }
+}
+
+impl<S, F, G, T, E> Stream for MapOrRecover<S, F, G>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> Result<T, E>,
+    G: FnMut(S::Item, E) -> T,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        Poll::Ready(
+            ready!(this.inner.poll_next(cx)).map(|item| match (this.f)(&item) {
+                Ok(mapped) => mapped,
+                Err(error) => (this.recover)(item, error),
+            }),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S> MapOrRecoverStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn failures_are_recovered_from_the_input() {
+        assert_eq!(
+            stream::iter(["1", "two", "3", ""])
+                .map_or_recover(|s| s.parse::<usize>(), |s, _| s.len() * 100)
+                .collect::<Vec<_>>()
+                .await,
+            vec![1, 300, 3, 0]
+        );
+    }
+
+    #[tokio::test]
+    async fn recovery_sees_the_error() {
+        assert_eq!(
+            stream::iter([4, -1, 9])
+                .map_or_recover(
+                    |x| if *x >= 0 { Ok(*x * 2) } else { Err("negative") },
+                    |x, e| {
+                        assert_eq!(e, "negative");
+                        -x
+                    },
+                )
+                .collect::<Vec<_>>()
+                .await,
+            vec![8, 1, 18]
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use std::{
+    ops::ControlFlow,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{stream::FusedStream, Stream};
+
+pub trait MapWhileInclusiveStreamExt: Stream + Sized {
+    /// Map each item with `f`, ending the stream right after the first item mapped to `ControlFlow::Break`.
+    ///
+    /// Unlike `map_while`, the breaking item is still yielded.
+    fn map_while_inclusive<F, T>(self, f: F) -> MapWhileInclusive<Self, F>
+    where
+        F: FnMut(Self::Item) -> ControlFlow<T, T>,
+    {
+        MapWhileInclusive::new(self, f)
+    }
+}
+
+/// Stream for [`map_while_inclusive`](`MapWhileInclusiveStreamExt::map_while_inclusive`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct MapWhileInclusive<Stream, F> {
+    #[pin]
+    inner: Stream,
+    f: F,
+
+    terminated: bool,
+}
+
+impl<S, F> MapWhileInclusive<S, F> {
+    pub fn new(inner: S, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            terminated: false,
+        }
+    }
+}
+
+impl<S, F, T> Stream for MapWhileInclusive<S, F>
+where
+    S: Stream,
+    F: FnMut(S::Item) -> ControlFlow<T, T>,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        if *this.terminated {
+            return Poll::Ready(None);
+        }
+
+        let Some(item) = ready!(this.inner.poll_next(cx)) else {
+            *this.terminated = true;
+            return Poll::Ready(None);
+        };
+        Poll::Ready(Some(match (this.f)(item) {
+            ControlFlow::Continue(mapped) => mapped,
+            ControlFlow::Break(mapped) => {
+                *this.terminated = true;
+                mapped
+            }
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.terminated {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.inner.size_hint();
+        (lower.min(1), upper)
+    }
+}
+
+impl<S, F, T> FusedStream for MapWhileInclusive<S, F>
+where
+    S: Stream,
+    F: FnMut(S::Item) -> ControlFlow<T, T>,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<S> MapWhileInclusiveStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn the_breaking_item_is_yielded() {
+        assert_eq!(
+            stream::iter([1, 2, 3, 4])
+                .map_while_inclusive(|n| if n == 3 {
+                    ControlFlow::Break(n)
+                } else {
+                    ControlFlow::Continue(n)
+                })
+                .collect::<Vec<_>>()
+                .await,
+            vec![1, 2, 3]
+        );
+    }
+
+    #[tokio::test]
+    async fn without_a_break_every_item_is_yielded() {
+        assert_eq!(
+            stream::iter([1, 2, 3, 4])
+                .map_while_inclusive(|n| ControlFlow::<i32, _>::Continue(n * 10))
+                .collect::<Vec<_>>()
+                .await,
+            vec![10, 20, 30, 40]
+        );
+    }
+
+    #[tokio::test]
+    async fn the_upstream_is_not_polled_after_a_break() {
+        let mut mapped = stream::iter([1, 2])
+            .chain(stream::pending())
+            .map_while_inclusive(ControlFlow::Break);
+
+        assert_eq!(mapped.next().await, Some(1));
+        assert!(mapped.is_terminated());
+        assert_eq!(mapped.next().await, None);
+    }
+}
@@ -0,0 +1,122 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait MapWithContextStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: Clone,
+{
+    /// Map each item with access to its index and the previous upstream item.
+    ///
+    /// `f` receives `(index, previous, current)`; the first call gets index `0` and no previous item.
+    fn map_with_context<F, T>(self, f: F) -> MapWithContext<Self, F, Self::Item>
+    where
+        F: FnMut(usize, Option<&Self::Item>, Self::Item) -> T,
+    {
+        MapWithContext::new(self, f)
+    }
+}
+
+/// Stream for [`map_with_context`](`MapWithContextStreamExt::map_with_context`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct MapWithContext<Stream, F, Item> {
+    #[pin]
+    inner: Stream,
+    f: F,
+
+    index: usize,
+    previous: Option<Item>,
+}
+
+impl<S, F> MapWithContext<S, F, S::Item>
+where
+    S: Stream,
+{
+    pub fn new(inner: S, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            index: 0,
+            previous: None,
+        }
+    }
+}
+
+impl<S, F, T> Stream for MapWithContext<S, F, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+    F: FnMut(usize, Option<&S::Item>, S::Item) -> T,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        let Some(item) = ready!(this.inner.poll_next(cx)) else {
+            return Poll::Ready(None);
+        };
+
+        let previous = this.previous.replace(item.clone());
+        let out = (this.f)(*this.index, previous.as_ref(), item);
+        *this.index += 1;
+
+        Poll::Ready(Some(out))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S> MapWithContextStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<i32>()
+            .map_with_context(|_, _, x| x)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn first_call_has_no_previous() {
+        assert_eq!(
+            stream::iter(["a", "b", "c"])
+                .map_with_context(|i, prev, cur| (i, prev.copied(), cur))
+                .collect::<Vec<_>>()
+                .await,
+            vec![(0, None, "a"), (1, Some("a"), "b"), (2, Some("b"), "c")]
+        );
+    }
+
+    #[tokio::test]
+    async fn value_depends_on_index_and_previous() {
+        assert_eq!(
+            stream::iter([5, 7, 4, 10])
+                .map_with_context(|i, prev, cur| i as i32 * 100 + cur - prev.copied().unwrap_or(0))
+                .collect::<Vec<_>>()
+                .await,
+            vec![5, 102, 197, 306]
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait MergeByTimestampStreamExt: Stream + Sized {
+    /// Merge two streams into one ordered by the timestamps extracted with `ts`.
+    ///
+    /// One item of each side is buffered and the one with the smaller timestamp is emitted (the left one on a tie).
+    /// The output is time-ordered only if both inputs are individually sorted by timestamp.
+    /// An item is emitted only once the other side has either yielded its next item or ended.
+    fn merge_by_timestamp<R, F>(self, right: R, ts: F) -> MergeByTimestamp<Self, R, F, Self::Item>
+    where
+        R: Stream<Item = Self::Item>,
+        F: FnMut(&Self::Item) -> u64,
+    {
+        MergeByTimestamp::new(self, right, ts)
+    }
+}
+
+/// Stream for [`merge_by_timestamp`](`MergeByTimestampStreamExt::merge_by_timestamp`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct MergeByTimestamp<L, R, F, Item> {
+    #[pin]
+    left: L,
+    #[pin]
+    right: R,
+    ts: F,
+
+    left_head: Option<(u64, Item)>,
+    right_head: Option<(u64, Item)>,
+    left_done: bool,
+    right_done: bool,
+}
+
+impl<L, R, F> MergeByTimestamp<L, R, F, L::Item>
+where
+    L: Stream,
+{
+    pub fn new(left: L, right: R, ts: F) -> Self {
+        Self {
+            left,
+            right,
+            ts,
+            left_head: None,
+            right_head: None,
+            left_done: false,
+            right_done: false,
+        }
+    }
+}
+
+impl<L, R, F> Stream for MergeByTimestamp<L, R, F, L::Item>
+where
+    L: Stream,
+    R: Stream<Item = L::Item>,
+    F: FnMut(&L::Item) -> u64,
+{
+    type Item = L::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        if this.left_head.is_none() && !*this.left_done {
+            match this.left.poll_next(cx) {
+                Poll::Pending => (),
+                Poll::Ready(None) => *this.left_done = true,
+                Poll::Ready(Some(item)) => *this.left_head = Some(((this.ts)(&item), item)),
+            }
+        }
+        if this.right_head.is_none() && !*this.right_done {
+            match this.right.poll_next(cx) {
+                Poll::Pending => (),
+                Poll::Ready(None) => *this.right_done = true,
+                Poll::Ready(Some(item)) => *this.right_head = Some(((this.ts)(&item), item)),
+            }
+        }
+
+        let left_known = this.left_head.is_some() || *this.left_done;
+        let right_known = this.right_head.is_some() || *this.right_done;
+        if !left_known || !right_known {
+            return Poll::Pending;
+        }
+
+        let take_left = match (this.left_head.as_ref(), this.right_head.as_ref()) {
+            (None, None) => return Poll::Ready(None),
+            (Some((l, _)), Some((r, _))) => l <= r,
+            (left, _) => left.is_some(),
+        };
+        let head = if take_left {
+            this.left_head.take()
+        } else {
+            this.right_head.take()
+        };
+
+        Poll::Ready(head.map(|(_, item)| item))
+    }
+}
+
+impl<S> MergeByTimestampStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{channel::mpsc, poll, stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sorted_inputs_are_merged_in_order() {
+        let left = stream::iter([(1, "l1"), (4, "l4"), (5, "l5"), (9, "l9")]);
+        let right = stream::iter([(2, "r2"), (3, "r3"), (5, "r5"), (10, "r10"), (11, "r11")]);
+
+        assert_eq!(
+            left.merge_by_timestamp(right, |(ts, _)| *ts)
+                .map(|(_, name)| name)
+                .collect::<Vec<_>>()
+                .await,
+            vec!["l1", "r2", "r3", "l4", "l5", "r5", "l9", "r10", "r11"]
+        );
+    }
+
+    #[tokio::test]
+    async fn an_empty_side_yields_the_other() {
+        assert_eq!(
+            stream::empty()
+                .merge_by_timestamp(stream::iter([3, 1, 2]), |x| *x)
+                .collect::<Vec<_>>()
+                .await,
+            vec![3, 1, 2]
+        );
+    }
+
+    #[tokio::test]
+    async fn waits_for_the_other_side_before_emitting() {
+        let (right_tx, right_rx) = mpsc::unbounded();
+        let mut merged = stream::iter([5u64, 7]).merge_by_timestamp(right_rx, |x| *x);
+
+        assert_eq!(poll!(merged.next()), Poll::Pending);
+
+        right_tx.unbounded_send(3).unwrap();
+        assert_eq!(poll!(merged.next()), Poll::Ready(Some(3)));
+        assert_eq!(poll!(merged.next()), Poll::Pending);
+
+        right_tx.unbounded_send(6).unwrap();
+        assert_eq!(poll!(merged.next()), Poll::Ready(Some(5)));
+        assert_eq!(poll!(merged.next()), Poll::Ready(Some(6)));
+
+        drop(right_tx);
+        assert_eq!(poll!(merged.next()), Poll::Ready(Some(7)));
+        assert_eq!(poll!(merged.next()), Poll::Ready(None));
+    }
+}
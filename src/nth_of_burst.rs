@@ -0,0 +1,130 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+use crate::drain;
+
+pub trait NthOfBurstStreamExt: Stream + Sized {
+    /// Emit only the item at position `index` (zero-based) of each burst: a run of items the upstream yields
+    /// without returning `Pending`.
+    ///
+    /// Bursts shorter than `index + 1` items yield nothing. A burst is drained a bounded number of items per poll
+    /// and tracked across the polls.
+    fn nth_of_burst(self, index: usize) -> NthOfBurst<Self> {
+        NthOfBurst::new(self, index)
+    }
+}
+
+/// Stream for [`nth_of_burst`](`NthOfBurstStreamExt::nth_of_burst`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct NthOfBurst<Stream> {
+    #[pin]
+    inner: Stream,
+    index: usize,
+
+    position: usize,
+}
+
+impl<S> NthOfBurst<S> {
+    pub fn new(inner: S, index: usize) -> Self {
+        Self {
+            inner,
+            index,
+            position: 0,
+        }
+    }
+}
+
+impl<S> Stream for NthOfBurst<S>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        let mut budget = drain::BUDGET;
+        loop {
+            if budget == 0 {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            budget -= 1;
+            match this.inner.as_mut().poll_next(cx) {
+                Poll::Pending => {
+                    *this.position = 0;
+                    return Poll::Pending;
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Ready(Some(item)) => {
+                    let position = *this.position;
+                    *this.position += 1;
+                    if position == *this.index {
+                        return Poll::Ready(Some(item));
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<S> NthOfBurstStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+    use crate::test_utils::ready_after_n_polls;
+
+    #[tokio::test]
+    async fn the_third_item_of_each_burst() {
+        assert_eq!(
+            stream::iter([[1, 2, 3, 4, 5], [6, 7, 8, 9, 10], [11, 12, 13, 14, 15]])
+                .map(stream::iter)
+                .then(|chunk| ready_after_n_polls(chunk, 1))
+                .flatten()
+                .nth_of_burst(2)
+                .collect::<Vec<_>>()
+                .await,
+            vec![3, 8, 13]
+        );
+    }
+
+    #[tokio::test]
+    async fn short_bursts_yield_nothing() {
+        assert_eq!(
+            stream::iter([vec![1, 2], vec![3, 4, 5], vec![6]])
+                .map(stream::iter)
+                .then(|chunk| ready_after_n_polls(chunk, 1))
+                .flatten()
+                .nth_of_burst(2)
+                .collect::<Vec<_>>()
+                .await,
+            vec![5]
+        );
+    }
+
+    #[tokio::test]
+    async fn a_long_burst_is_tracked_across_polls() {
+        use futures::poll;
+
+        let mut nth = stream::repeat(1).nth_of_burst(2);
+        assert_eq!(poll!(nth.next()), Poll::Ready(Some(1)));
+        assert_eq!(poll!(nth.next()), Poll::Pending);
+
+        assert_eq!(
+            stream::iter(0..100)
+                .chain(stream::pending())
+                .nth_of_burst(50)
+                .next()
+                .await,
+            Some(50)
+        );
+    }
+}
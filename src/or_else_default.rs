@@ -0,0 +1,101 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait OrElseDefaultStreamExt: Stream + Sized {
+    /// Forward the upstream items; if the upstream ends without producing any, emit `default` once.
+    fn or_else_default(self, default: Self::Item) -> OrElseDefault<Self, Self::Item> {
+        OrElseDefault::new(self, default)
+    }
+}
+
+/// Stream for [`or_else_default`](`OrElseDefaultStreamExt::or_else_default`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct OrElseDefault<Stream, Item> {
+    #[pin]
+    inner: Stream,
+
+    default: Option<Item>,
+    done: bool,
+}
+
+impl<S, T> OrElseDefault<S, T> {
+    pub fn new(inner: S, default: T) -> Self {
+        Self {
+            inner,
+            default: Some(default),
+            done: false,
+        }
+    }
+}
+
+impl<S> Stream for OrElseDefault<S, S::Item>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        let Some(item) = ready!(this.inner.poll_next(cx)) else {
+            *this.done = true;
+            return Poll::Ready(this.default.take());
+        };
+        *this.default = None;
+        Poll::Ready(Some(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.inner.size_hint();
+        if self.default.is_some() {
+            (lower.max(1), upper.map(|upper| upper.max(1)))
+        } else {
+            (lower, upper)
+        }
+    }
+}
+
+impl<S> OrElseDefaultStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn empty_stream_emits_the_default() {
+        assert_eq!(
+            stream::empty()
+                .or_else_default(42)
+                .collect::<Vec<_>>()
+                .await,
+            vec![42]
+        );
+    }
+
+    #[tokio::test]
+    async fn non_empty_stream_suppresses_the_default() {
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .or_else_default(42)
+                .collect::<Vec<_>>()
+                .await,
+            vec![1, 2, 3]
+        );
+    }
+}
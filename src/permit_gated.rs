@@ -0,0 +1,192 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+use crate::{drain, with_high_water::Buffered};
+
+pub trait PermitGatedStreamExt: Stream + Sized {
+    /// Emit an item only when a permit arrives from `permits`: each permit grants exactly one emission.
+    ///
+    /// The upstream is drained eagerly, a bounded number of items per poll; while no permit is available the items
+    /// are kept according to `buffering`.
+    /// The permit stream is polled only while there is something to emit, so the permits are never hoarded.
+    /// The stream ends when the upstream has ended and the buffer is empty, or when `permits` ends.
+    fn permit_gated<P>(
+        self,
+        permits: P,
+        buffering: GateBuffering,
+    ) -> PermitGated<Self, P, Self::Item>
+    where
+        P: Stream,
+    {
+        PermitGated::new(self, permits, buffering)
+    }
+}
+
+/// How [`PermitGated`] keeps the items that arrive while no permit is available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GateBuffering {
+    /// Keep every item, emitting them in order. The buffer is unbounded.
+    Fifo,
+    /// Keep only the most recent item.
+    Latest,
+}
+
+/// Stream for [`permit_gated`](`PermitGatedStreamExt::permit_gated`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct PermitGated<Stream, Permits, Item> {
+    #[pin]
+    inner: Stream,
+    #[pin]
+    permits: Permits,
+    buffering: GateBuffering,
+
+    buffer: VecDeque<Item>,
+    inner_done: bool,
+    terminated: bool,
+}
+
+impl<S, P, I> PermitGated<S, P, I> {
+    pub fn new(inner: S, permits: P, buffering: GateBuffering) -> Self {
+        Self {
+            inner,
+            permits,
+            buffering,
+            buffer: VecDeque::new(),
+            inner_done: false,
+            terminated: false,
+        }
+    }
+}
+
+impl<S, P> Stream for PermitGated<S, P, S::Item>
+where
+    S: Stream,
+    P: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.terminated {
+            return Poll::Ready(None);
+        }
+
+        let mut budget = drain::BUDGET;
+        while !*this.inner_done {
+            if budget == 0 {
+                cx.waker().wake_by_ref();
+                break;
+            }
+            budget -= 1;
+            match this.inner.as_mut().poll_next(cx) {
+                Poll::Pending => break,
+                Poll::Ready(None) => *this.inner_done = true,
+                Poll::Ready(Some(item)) => {
+                    if *this.buffering == GateBuffering::Latest {
+                        this.buffer.clear();
+                    }
+                    this.buffer.push_back(item);
+                }
+            }
+        }
+
+        if this.buffer.is_empty() {
+            return if *this.inner_done {
+                *this.terminated = true;
+                Poll::Ready(None)
+            } else {
+                Poll::Pending
+            };
+        }
+
+        match this.permits.as_mut().poll_next(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Some(_permit)) => Poll::Ready(this.buffer.pop_front()),
+            Poll::Ready(None) => {
+                *this.terminated = true;
+                this.buffer.clear();
+                Poll::Ready(None)
+            }
+        }
+    }
+}
+
+impl<S, P, I> Buffered for PermitGated<S, P, I> {
+    /// Number of items waiting for a permit.
+    fn buffered_len(&self) -> usize {
+        self.buffer.len()
+    }
+}
+
+impl<S> PermitGatedStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{channel::mpsc, poll, stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn nothing_is_emitted_without_a_permit() {
+        let (permit_tx, permit_rx) = mpsc::unbounded::<()>();
+        let mut gated = stream::iter([1, 2, 3]).permit_gated(permit_rx, GateBuffering::Fifo);
+
+        assert_eq!(poll!(gated.next()), Poll::Pending);
+        assert_eq!(gated.buffered_len(), 3);
+
+        permit_tx.unbounded_send(()).unwrap();
+        assert_eq!(poll!(gated.next()), Poll::Ready(Some(1)));
+        assert_eq!(poll!(gated.next()), Poll::Pending);
+
+        permit_tx.unbounded_send(()).unwrap();
+        permit_tx.unbounded_send(()).unwrap();
+        assert_eq!(poll!(gated.next()), Poll::Ready(Some(2)));
+        assert_eq!(poll!(gated.next()), Poll::Ready(Some(3)));
+        assert_eq!(poll!(gated.next()), Poll::Ready(None));
+    }
+
+    #[tokio::test]
+    async fn latest_buffering_keeps_only_the_last_item() {
+        let (permit_tx, permit_rx) = mpsc::unbounded::<()>();
+        let mut gated = stream::iter([1, 2, 3]).permit_gated(permit_rx, GateBuffering::Latest);
+
+        assert_eq!(poll!(gated.next()), Poll::Pending);
+        permit_tx.unbounded_send(()).unwrap();
+        permit_tx.unbounded_send(()).unwrap();
+        assert_eq!(poll!(gated.next()), Poll::Ready(Some(3)));
+        assert_eq!(poll!(gated.next()), Poll::Ready(None));
+    }
+
+    #[tokio::test]
+    async fn permits_are_not_consumed_while_idle() {
+        let (item_tx, item_rx) = mpsc::unbounded();
+        let mut gated = item_rx.permit_gated(stream::iter([(); 2]), GateBuffering::Fifo);
+
+        assert_eq!(poll!(gated.next()), Poll::Pending);
+        item_tx.unbounded_send(1).unwrap();
+        assert_eq!(poll!(gated.next()), Poll::Ready(Some(1)));
+        item_tx.unbounded_send(2).unwrap();
+        item_tx.unbounded_send(3).unwrap();
+        assert_eq!(poll!(gated.next()), Poll::Ready(Some(2)));
+        assert_eq!(poll!(gated.next()), Poll::Ready(None));
+    }
+
+    #[tokio::test]
+    async fn an_always_ready_upstream_is_drained_in_bounded_steps() {
+        let (permit_tx, permit_rx) = mpsc::unbounded::<()>();
+        let mut gated = stream::repeat(1).permit_gated(permit_rx, GateBuffering::Fifo);
+
+        assert_eq!(poll!(gated.next()), Poll::Pending);
+        assert_eq!(gated.buffered_len(), drain::BUDGET);
+
+        permit_tx.unbounded_send(()).unwrap();
+        assert_eq!(poll!(gated.next()), Poll::Ready(Some(1)));
+    }
+}
@@ -0,0 +1,102 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait PollRetryStreamExt: Stream + Sized {
+    /// Re-poll the upstream up to `k` more times within the same `poll_next` when it returns `Pending`.
+    ///
+    /// This is a workaround for misbehaving streams that return `Pending` without registering the waker,
+    /// which would otherwise hang the task. Well-behaved streams do not need it.
+    fn poll_retry(self, k: usize) -> PollRetry<Self> {
+        PollRetry::new(self, k)
+    }
+}
+
+/// Stream for [`poll_retry`](`PollRetryStreamExt::poll_retry`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct PollRetry<Stream> {
+    #[pin]
+    inner: Stream,
+    k: usize,
+}
+
+impl<S> PollRetry<S> {
+    pub fn new(inner: S, k: usize) -> Self {
+        Self { inner, k }
+    }
+}
+
+impl<S> Stream for PollRetry<S>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        for _ in 0..*this.k {
+            if let ready @ Poll::Ready(_) = this.inner.as_mut().poll_next(cx) {
+                return ready;
+            }
+        }
+        this.inner.as_mut().poll_next(cx)
+    }
+}
+
+impl<S> PollRetryStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{poll, stream, StreamExt};
+
+    use super::*;
+
+    /// Returns `Pending` `pending` times before each item, never waking the task.
+    fn forgetful(pending: usize) -> impl Stream<Item = usize> + Unpin {
+        let mut polls = 0;
+        stream::poll_fn(move |_cx| {
+            polls += 1;
+            if polls % (pending + 1) == 0 {
+                Poll::Ready(Some(polls))
+            } else {
+                Poll::Pending
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn without_retries_the_item_is_not_reached() {
+        let mut s = forgetful(1).poll_retry(0);
+        assert_eq!(poll!(s.next()), Poll::Pending);
+    }
+
+    #[tokio::test]
+    async fn a_single_outer_poll_yields_the_item() {
+        let mut s = forgetful(1).poll_retry(1);
+        assert_eq!(poll!(s.next()), Poll::Ready(Some(2)));
+        assert_eq!(poll!(s.next()), Poll::Ready(Some(4)));
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_k_retries() {
+        let mut s = forgetful(3).poll_retry(2);
+        assert_eq!(poll!(s.next()), Poll::Pending);
+        assert_eq!(poll!(s.next()), Poll::Ready(Some(4)));
+    }
+
+    #[tokio::test]
+    async fn end_of_stream_is_forwarded() {
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .poll_retry(3)
+                .collect::<Vec<_>>()
+                .await,
+            vec![1, 2, 3]
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait PollWeightedAverageStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: Into<f64>,
+{
+    /// On each item, emit the mean of the current value over the last `window` polls.
+    ///
+    /// Every poll samples the latest value (the one arriving with the poll, or the previous one while the upstream
+    /// is pending), so each value is weighted by the number of polls it persisted. Polls made before the first item
+    /// are not sampled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    fn poll_weighted_average(self, window: usize) -> PollWeightedAverage<Self> {
+        PollWeightedAverage::new(self, window)
+    }
+}
+
+/// Stream for [`poll_weighted_average`](`PollWeightedAverageStreamExt::poll_weighted_average`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct PollWeightedAverage<Stream> {
+    #[pin]
+    inner: Stream,
+    window: usize,
+
+    current: Option<f64>,
+    samples: VecDeque<f64>,
+}
+
+impl<S> PollWeightedAverage<S> {
+    pub fn new(inner: S, window: usize) -> Self {
+        assert!(window > 0, "the window must not be empty");
+        Self {
+            inner,
+            window,
+            current: None,
+            samples: VecDeque::with_capacity(window),
+        }
+    }
+}
+
+impl<S> Stream for PollWeightedAverage<S>
+where
+    S: Stream,
+    S::Item: Into<f64>,
+{
+    type Item = f64;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        let fresh = match this.inner.poll_next(cx) {
+            Poll::Ready(None) => return Poll::Ready(None),
+            Poll::Ready(Some(x)) => {
+                *this.current = Some(x.into());
+                true
+            }
+            Poll::Pending => false,
+        };
+
+        if let Some(current) = *this.current {
+            if this.samples.len() == *this.window {
+                this.samples.pop_front();
+            }
+            this.samples.push_back(current);
+        }
+
+        if !fresh {
+            return Poll::Pending;
+        }
+        let mean = this.samples.iter().sum::<f64>() / this.samples.len() as f64;
+        Poll::Ready(Some(mean))
+    }
+}
+
+impl<S> PollWeightedAverageStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Into<f64>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+    use crate::test_utils::ready_after_n_polls;
+
+    #[tokio::test]
+    async fn ready_items_are_weighted_equally() {
+        assert_eq!(
+            stream::iter([1.0, 2.0, 3.0, 4.0])
+                .poll_weighted_average(2)
+                .collect::<Vec<_>>()
+                .await,
+            vec![1.0, 1.5, 2.5, 3.5]
+        );
+    }
+
+    #[tokio::test]
+    async fn values_are_weighted_by_their_persistence() {
+        assert_eq!(
+            stream::iter([(0.0, 0), (10.0, 3)])
+                .then(|(x, polls)| ready_after_n_polls(x, polls))
+                .poll_weighted_average(5)
+                .collect::<Vec<_>>()
+                .await,
+            vec![0.0, 2.0]
+        );
+    }
+
+    #[tokio::test]
+    async fn old_polls_fall_out_of_the_window() {
+        assert_eq!(
+            stream::iter([(4u8, 0), (8, 1), (0, 2)])
+                .then(|(x, polls)| ready_after_n_polls(x, polls))
+                .poll_weighted_average(4)
+                .collect::<Vec<_>>()
+                .await,
+            vec![4.0, 16.0 / 3.0, 6.0]
+        );
+    }
+}
@@ -1,6 +1,108 @@
+//! All the extension traits of the crate, for a glob import.
+//!
+//! ```
+//! use futures::{executor::block_on, stream, StreamExt};
+//! use streams::prelude::*;
+//!
+//! let expanded = stream::iter([1, 2]).expand().take(2);
+//! let mut latest = stream::iter([3, 4]).chain(stream::pending()).latest_ready();
+//! let zipped = stream::iter(['a', 'b']).zip_biased(stream::iter([true, false]));
+//!
+//! assert_eq!(block_on(expanded.collect::<Vec<_>>()), vec![1, 2]);
+//! assert_eq!(block_on(latest.next()), Some(4));
+//! assert_eq!(
+//!     block_on(zipped.collect::<Vec<_>>()),
+//!     vec![('a', true), ('b', false)]
+//! );
+//! ```
+
+pub use crate::accumulate::AccumulateStreamExt;
+pub use crate::arrival_delta_polls::ArrivalDeltaPollsStreamExt;
+#[cfg(feature = "tokio")]
+pub use crate::block_on_iter::BlockOnIterStreamExt;
+pub use crate::buffer_until::BufferUntilStreamExt;
+pub use crate::burst_id::BurstIdStreamExt;
+pub use crate::cap_per_window::CapPerWindowStreamExt;
+pub use crate::chunks_strided::ChunksStridedStreamExt;
+pub use crate::collect_array::CollectArrayStreamExt;
+pub use crate::combine_latest::CombineLatestStreamExt;
+pub use crate::combine_latest_fresh::CombineLatestFreshStreamExt;
+pub use crate::debounce::DebounceStreamExt;
+pub use crate::debounced_distinct_polls::DebouncedDistinctPollsStreamExt;
+pub use crate::dedup::DedupStreamExt;
+pub use crate::dedup::TryDedupStreamExt;
+pub use crate::dedup_consecutive_errors::DedupConsecutiveErrorsStreamExt;
+pub use crate::dedup_recent::DedupRecentStreamExt;
+pub use crate::demux::DemuxStreamExt;
+pub use crate::derivatives::DerivativesStreamExt;
+pub use crate::detect_gaps::DetectGapsStreamExt;
+pub use crate::drop_out_of_order::DropOutOfOrderStreamExt;
+pub use crate::ensure_alternating::EnsureAlternatingStreamExt;
 pub use crate::expand::ExpandStreamExt;
 pub use crate::expand::TryExpandStreamExt;
+pub use crate::filter_window::FilterWindowStreamExt;
+pub use crate::fir_filter::FirFilterStreamExt;
+pub use crate::first_ok::FirstOkStreamExt;
+pub use crate::flat_map_iter::FlatMapIterStreamExt;
+pub use crate::fold_to_one::FoldToOneStreamExt;
+pub use crate::fold_to_one::TryFoldToOneStreamExt;
+pub use crate::forward_latest::ForwardLatestStreamExt;
+pub use crate::fsm::FsmStreamExt;
+pub use crate::group_consecutive_capped::GroupConsecutiveCappedStreamExt;
+pub use crate::hold::HoldStreamExt;
+pub use crate::hysteresis::HysteresisStreamExt;
+#[cfg(feature = "test-support")]
+pub use crate::inject_errors::InjectErrorsStreamExt;
+pub use crate::integrate::IntegrateStreamExt;
+pub use crate::interleave_snapshots::InterleaveSnapshotsStreamExt;
+#[cfg(feature = "tokio")]
+pub use crate::into_mpsc_latest::IntoMpscLatestStreamExt;
+pub use crate::join_within::JoinWithinStreamExt;
+pub use crate::latest_flagged::LatestFlaggedStreamExt;
 pub use crate::latest_ready::LatestReadyStreamExt;
 pub use crate::latest_ready::TryLatestReadyStreamExt;
+pub use crate::leaky_integrate::LeakyIntegrateStreamExt;
+pub use crate::map_if::MapIfStreamExt;
+pub use crate::map_or_recover::MapOrRecoverStreamExt;
+pub use crate::map_while_inclusive::MapWhileInclusiveStreamExt;
+pub use crate::map_with_context::MapWithContextStreamExt;
+pub use crate::merge_by_timestamp::MergeByTimestampStreamExt;
+pub use crate::nth_of_burst::NthOfBurstStreamExt;
+pub use crate::or_else_default::OrElseDefaultStreamExt;
+pub use crate::permit_gated::PermitGatedStreamExt;
+pub use crate::poll_retry::PollRetryStreamExt;
+pub use crate::poll_weighted_average::PollWeightedAverageStreamExt;
+pub use crate::ratchet::RatchetStreamExt;
+pub use crate::ready_chunks_biased::ReadyChunksBiasedStreamExt;
+pub use crate::reduce_chunks::ReduceChunksStreamExt;
+pub use crate::reduce_until::ReduceUntilStreamExt;
+pub use crate::repeat_each::RepeatEachStreamExt;
+pub use crate::resample_latest::ResampleLatestStreamExt;
+pub use crate::ring::RingStreamExt;
+pub use crate::rolling_correlation::RollingCorrelationStreamExt;
+pub use crate::rolling_sum_checked::RollingSumCheckedStreamExt;
+pub use crate::rolling_zscore::RollingZscoreStreamExt;
+pub use crate::running_extremes::RunningExtremesStreamExt;
+pub use crate::running_percentile::RunningPercentileStreamExt;
+pub use crate::running_stats::RunningStatsStreamExt;
+pub use crate::sample::SampleStreamExt;
+pub use crate::sessionize_polls::SessionizePollsStreamExt;
+pub use crate::settle_within::SettleWithinStreamExt;
+pub use crate::skip_first::SkipFirstStreamExt;
+pub use crate::snapshot_on::SnapshotOnStreamExt;
+pub use crate::split_at_first::SplitAtFirstStreamExt;
+pub use crate::split_by_threshold::SplitByThresholdStreamExt;
+pub use crate::stable_burst::StableBurstStreamExt;
+pub use crate::stamp_with::StampWithStreamExt;
+pub use crate::stateful_by_key_lru::StatefulByKeyLruStreamExt;
+pub use crate::throttle::ThrottleStreamExt;
+#[cfg(feature = "time")]
+pub use crate::throttle_counted::ThrottleCountedStreamExt;
+pub use crate::with_high_water::WithHighWaterStreamExt;
+pub use crate::with_latest_from::WithLatestFromStreamExt;
+pub use crate::with_periodic_aggregate::WithPeriodicAggregateStreamExt;
+pub use crate::with_recovery_depth::WithRecoveryDepthStreamExt;
+pub use crate::with_termination_marker::WithTerminationMarkerStreamExt;
 pub use crate::zip_biased::TryZipBiasedStreamExt;
 pub use crate::zip_biased::ZipBiasedStreamExt;
+pub use crate::zip_indexed::ZipIndexedStreamExt;
@@ -0,0 +1,106 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait RatchetStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: PartialOrd + Clone,
+{
+    /// Emit the first item and then only the items strictly greater than every item emitted before.
+    fn ratchet(self) -> Ratchet<Self, Self::Item> {
+        Ratchet::new(self)
+    }
+}
+
+/// Stream for [`ratchet`](`RatchetStreamExt::ratchet`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct Ratchet<Stream, Item> {
+    #[pin]
+    inner: Stream,
+
+    max: Option<Item>,
+}
+
+impl<S> Ratchet<S, S::Item>
+where
+    S: Stream,
+    S::Item: PartialOrd + Clone,
+{
+    pub fn new(inner: S) -> Self {
+        Self { inner, max: None }
+    }
+}
+
+impl<S> Stream for Ratchet<S, S::Item>
+where
+    S: Stream,
+    S::Item: PartialOrd + Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            let Some(item) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                break None;
+            };
+            if this.max.as_ref().is_none_or(|max| item > *max) {
+                *this.max = Some(item.clone());
+                break Some(item);
+            }
+        })
+    }
+}
+
+impl<S> RatchetStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: PartialOrd + Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<i32>()
+            .ratchet()
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn regressions_are_suppressed() {
+        assert_eq!(
+            stream::iter([1, 3, 2, 5, 4, 6])
+                .ratchet()
+                .collect::<Vec<_>>()
+                .await,
+            vec![1, 3, 5, 6]
+        );
+    }
+
+    #[tokio::test]
+    async fn equal_values_are_suppressed() {
+        assert_eq!(
+            stream::iter([2.0, 2.0, 2.5, 2.5])
+                .ratchet()
+                .collect::<Vec<_>>()
+                .await,
+            vec![2.0, 2.5]
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{stream::FusedStream, Stream};
+
+use crate::with_high_water::Buffered;
+
+pub trait ReadyChunksBiasedStreamExt: Stream + Sized {
+    /// Drain every item the upstream has ready into a chunk, yielding it once the upstream is `Pending` or has ended.
+    ///
+    /// Unlike [`latest_ready`](`crate::latest_ready::LatestReadyStreamExt::latest_ready`), the whole burst is kept.
+    /// A chunk is yielded early once it holds `max_len` items; an empty chunk is never yielded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is zero.
+    fn ready_chunks_biased(self, max_len: usize) -> ReadyChunksBiased<Self> {
+        ReadyChunksBiased::new(self, max_len)
+    }
+}
+
+/// Stream for [`ready_chunks_biased`](`ReadyChunksBiasedStreamExt::ready_chunks_biased`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct ReadyChunksBiased<Stream> {
+    #[pin]
+    inner: Stream,
+    max_len: usize,
+
+    terminated: bool,
+}
+
+impl<S> ReadyChunksBiased<S> {
+    pub fn new(inner: S, max_len: usize) -> Self {
+        assert!(max_len > 0, "the chunk size must not be zero");
+        Self {
+            inner,
+            max_len,
+            terminated: false,
+        }
+    }
+}
+
+impl<S> Stream for ReadyChunksBiased<S>
+where
+    S: Stream,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.terminated {
+            return Poll::Ready(None);
+        }
+
+        let mut chunk = Vec::new();
+        while chunk.len() < *this.max_len {
+            match this.inner.as_mut().poll_next(cx) {
+                Poll::Pending => break,
+                Poll::Ready(Some(item)) => chunk.push(item),
+                Poll::Ready(None) => {
+                    *this.terminated = true;
+                    break;
+                }
+            }
+        }
+
+        if !chunk.is_empty() {
+            Poll::Ready(Some(chunk))
+        } else if *this.terminated {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.terminated {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.inner.size_hint();
+        (lower.div_ceil(self.max_len), upper)
+    }
+}
+
+impl<S> Buffered for ReadyChunksBiased<S> {
+    /// Always zero: a chunk is handed out within the poll that gathers it, so nothing is held between the polls.
+    fn buffered_len(&self) -> usize {
+        0
+    }
+}
+
+impl<S> FusedStream for ReadyChunksBiased<S>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<S> ReadyChunksBiasedStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{channel::mpsc, poll, stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn chunks_end_at_pending_points() {
+        let (tx, rx) = mpsc::unbounded();
+        let mut chunks = rx.ready_chunks_biased(10);
+
+        assert_eq!(poll!(chunks.next()), Poll::Pending);
+
+        tx.unbounded_send(1).unwrap();
+        tx.unbounded_send(2).unwrap();
+        tx.unbounded_send(3).unwrap();
+        assert_eq!(poll!(chunks.next()), Poll::Ready(Some(vec![1, 2, 3])));
+        assert_eq!(poll!(chunks.next()), Poll::Pending);
+
+        tx.unbounded_send(4).unwrap();
+        assert_eq!(poll!(chunks.next()), Poll::Ready(Some(vec![4])));
+
+        tx.unbounded_send(5).unwrap();
+        drop(tx);
+        assert_eq!(poll!(chunks.next()), Poll::Ready(Some(vec![5])));
+        assert_eq!(poll!(chunks.next()), Poll::Ready(None));
+        assert!(chunks.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn chunks_are_capped() {
+        assert_eq!(
+            stream::iter(1..=5)
+                .ready_chunks_biased(2)
+                .collect::<Vec<_>>()
+                .await,
+            vec![vec![1, 2], vec![3, 4], vec![5]]
+        );
+    }
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<()>()
+            .ready_chunks_biased(3)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_max_len_is_rejected() {
+        let _ = stream::empty::<()>().ready_chunks_biased(0);
+    }
+}
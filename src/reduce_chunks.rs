@@ -0,0 +1,141 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait ReduceChunksStreamExt: Stream + Sized {
+    /// Reduce each run of `n` consecutive items into one with `f`, folding them from the left.
+    ///
+    /// A partial final run is reduced and yielded too.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    fn reduce_chunks<F>(self, n: usize, f: F) -> ReduceChunks<Self, F, Self::Item>
+    where
+        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+    {
+        ReduceChunks::new(self, n, f)
+    }
+}
+
+/// Stream for [`reduce_chunks`](`ReduceChunksStreamExt::reduce_chunks`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct ReduceChunks<Stream, F, Item> {
+    #[pin]
+    inner: Stream,
+    n: usize,
+    f: F,
+
+    acc: Option<Item>,
+    count: usize,
+}
+
+impl<S, F, I> ReduceChunks<S, F, I> {
+    pub fn new(inner: S, n: usize, f: F) -> Self {
+        assert!(n > 0, "the chunk size must not be zero");
+        Self {
+            inner,
+            n,
+            f,
+            acc: None,
+            count: 0,
+        }
+    }
+}
+
+impl<S, F> Stream for ReduceChunks<S, F, S::Item>
+where
+    S: Stream,
+    F: FnMut(S::Item, S::Item) -> S::Item,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            let Some(item) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                *this.count = 0;
+                break this.acc.take();
+            };
+
+            *this.acc = Some(match this.acc.take() {
+                None => item,
+                Some(acc) => (this.f)(acc, item),
+            });
+            *this.count += 1;
+            if *this.count == *this.n {
+                *this.count = 0;
+                break this.acc.take();
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        let chunks = |items: usize| items.div_ceil(self.n);
+        (
+            chunks(lower.saturating_add(self.count)),
+            upper
+                .and_then(|upper| upper.checked_add(self.count))
+                .map(chunks),
+        )
+    }
+}
+
+impl<S> ReduceChunksStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn pairs_are_summed_and_the_remainder_is_flushed() {
+        assert_eq!(
+            stream::iter([1, 2, 3, 4, 5])
+                .reduce_chunks(2, |a, b| a + b)
+                .collect::<Vec<_>>()
+                .await,
+            vec![3, 7, 5]
+        );
+    }
+
+    #[tokio::test]
+    async fn the_reduction_folds_from_the_left() {
+        assert_eq!(
+            stream::iter(["a", "b", "c", "d", "e", "f"])
+                .map(String::from)
+                .reduce_chunks(3, |a, b| format!("({a}{b})"))
+                .collect::<Vec<_>>()
+                .await,
+            vec!["((ab)c)".to_owned(), "((de)f)".to_owned()]
+        );
+    }
+
+    #[test]
+    fn size_hint_counts_the_chunks() {
+        assert_eq!(
+            stream::iter([1, 2, 3, 4, 5])
+                .reduce_chunks(2, |a, b| a + b)
+                .size_hint(),
+            (3, Some(3))
+        );
+    }
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<i32>()
+            .reduce_chunks(2, |a, b| a + b)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+}
@@ -0,0 +1,117 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait ReduceUntilStreamExt: Stream + Sized {
+    /// Fold the stream into a single accumulator, yielded once either `done` holds for it or the upstream ends.
+    ///
+    /// `done` is checked after each folded item; once it returns `true` the upstream is not polled anymore.
+    fn reduce_until<A, F, P>(self, init: A, f: F, done: P) -> ReduceUntil<Self, A, F, P>
+    where
+        F: FnMut(A, Self::Item) -> A,
+        P: FnMut(&A) -> bool,
+    {
+        ReduceUntil::new(self, init, f, done)
+    }
+}
+
+/// Stream for [`reduce_until`](`ReduceUntilStreamExt::reduce_until`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct ReduceUntil<Stream, A, F, P> {
+    #[pin]
+    inner: Stream,
+    acc: Option<A>,
+    f: F,
+    done: P,
+}
+
+impl<S, A, F, P> ReduceUntil<S, A, F, P> {
+    pub fn new(inner: S, init: A, f: F, done: P) -> Self {
+        Self {
+            inner,
+            acc: Some(init),
+            f,
+            done,
+        }
+    }
+}
+
+impl<S, A, F, P> Stream for ReduceUntil<S, A, F, P>
+where
+    S: Stream,
+    F: FnMut(A, S::Item) -> A,
+    P: FnMut(&A) -> bool,
+{
+    type Item = A;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            if this.acc.is_none() {
+                break None;
+            }
+            let item = ready!(this.inner.as_mut().poll_next(cx));
+            let acc = this.acc.take().expect("checked above");
+
+            match item {
+                None => break Some(acc),
+                Some(item) => {
+                    let acc = (this.f)(acc, item);
+                    if (this.done)(&acc) {
+                        break Some(acc);
+                    }
+                    *this.acc = Some(acc);
+                }
+            }
+        })
+    }
+}
+
+impl<S> ReduceUntilStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn empty_stream_yields_init() {
+        assert_eq!(
+            stream::empty::<i32>()
+                .reduce_until(0, |acc, x| acc + x, |_| false)
+                .collect::<Vec<_>>()
+                .await,
+            vec![0]
+        );
+    }
+
+    #[tokio::test]
+    async fn folds_the_whole_stream_if_never_done() {
+        assert_eq!(
+            stream::iter([1, 2, 3, 4])
+                .reduce_until(0, |acc, x| acc + x, |acc| *acc > 100)
+                .collect::<Vec<_>>()
+                .await,
+            vec![10]
+        );
+    }
+
+    #[tokio::test]
+    async fn short_circuits_once_the_threshold_is_exceeded() {
+        assert_eq!(
+            stream::iter(1..)
+                .reduce_until(0, |acc, x| acc + x, |acc| *acc > 10)
+                .collect::<Vec<_>>()
+                .await,
+            vec![15]
+        );
+    }
+}
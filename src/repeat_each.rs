@@ -0,0 +1,152 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait RepeatEachStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: Clone,
+{
+    /// Yield each upstream item `k` times in a row before advancing.
+    ///
+    /// `k == 0` drops every item, `k == 1` leaves the stream as is.
+    fn repeat_each(self, k: usize) -> RepeatEach<Self, Self::Item> {
+        RepeatEach::new(self, k)
+    }
+}
+
+/// Stream for [`repeat_each`](`RepeatEachStreamExt::repeat_each`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct RepeatEach<Stream, Item> {
+    #[pin]
+    inner: Stream,
+    k: usize,
+
+    current: Option<Item>,
+    remaining: usize,
+}
+
+impl<S> RepeatEach<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    pub fn new(inner: S, k: usize) -> Self {
+        Self {
+            inner,
+            k,
+            current: None,
+            remaining: 0,
+        }
+    }
+}
+
+impl<S> Stream for RepeatEach<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        if *this.remaining > 0 {
+            *this.remaining -= 1;
+            let item = if *this.remaining == 0 {
+                this.current.take()
+            } else {
+                this.current.clone()
+            };
+            return Poll::Ready(item);
+        }
+
+        Poll::Ready(loop {
+            match ready!(this.inner.as_mut().poll_next(cx)) {
+                None => break None,
+                Some(_) if *this.k == 0 => continue,
+                Some(item) => {
+                    *this.remaining = *this.k - 1;
+                    if *this.remaining > 0 {
+                        *this.current = Some(item.clone());
+                    }
+                    break Some(item);
+                }
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        let lower = lower.saturating_mul(self.k).saturating_add(self.remaining);
+        let upper = upper
+            .and_then(|upper| upper.checked_mul(self.k))
+            .and_then(|upper| upper.checked_add(self.remaining));
+        (lower, upper)
+    }
+}
+
+impl<S> RepeatEachStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn each_item_is_repeated_k_times() {
+        assert_eq!(
+            stream::iter([1, 2])
+                .repeat_each(3)
+                .collect::<Vec<_>>()
+                .await,
+            vec![1, 1, 1, 2, 2, 2]
+        );
+    }
+
+    #[tokio::test]
+    async fn k_zero_drops_all_items() {
+        assert!(stream::iter([1, 2, 3])
+            .repeat_each(0)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn k_one_is_identity() {
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .repeat_each(1)
+                .collect::<Vec<_>>()
+                .await,
+            vec![1, 2, 3]
+        );
+    }
+
+    #[tokio::test]
+    async fn size_hint_is_multiplied_by_k() {
+        let mut repeated = stream::iter([1, 2]).repeat_each(3);
+        assert_eq!(repeated.size_hint(), (6, Some(6)));
+
+        assert_eq!(repeated.next().await, Some(1));
+        assert_eq!(repeated.size_hint(), (5, Some(5)));
+
+        assert_eq!(
+            stream::iter([1, 2]).repeat_each(0).size_hint(),
+            (0, Some(0))
+        );
+    }
+}
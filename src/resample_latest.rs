@@ -0,0 +1,161 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+use crate::drain;
+
+pub trait ResampleLatestStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: Clone,
+{
+    /// Yield the freshest item on every poll: the last one of each ready burst, or a repeat of the previous one
+    /// while the upstream is pending.
+    ///
+    /// This combines [`latest_ready`](`crate::latest_ready::LatestReadyStreamExt::latest_ready`) and
+    /// [`expand`](`crate::expand::ExpandStreamExt::expand`). The stream is pending only until the first item arrives.
+    /// A burst that is ended by the end of the upstream still yields its last item before the stream ends.
+    /// A burst is drained a bounded number of items per poll; a longer one yields the latest item of each poll.
+    fn resample_latest(self) -> ResampleLatest<Self, Self::Item> {
+        ResampleLatest::new(self)
+    }
+}
+
+/// Stream for [`resample_latest`](`ResampleLatestStreamExt::resample_latest`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct ResampleLatest<Stream, Item> {
+    #[pin]
+    inner: Stream,
+
+    last: Option<Item>,
+    terminated: bool,
+}
+
+impl<S> ResampleLatest<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            last: None,
+            terminated: false,
+        }
+    }
+}
+
+impl<S> Stream for ResampleLatest<S, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.terminated {
+            return Poll::Ready(None);
+        }
+
+        let mut fresh = None;
+        let mut budget = drain::BUDGET;
+        loop {
+            if budget == 0 {
+                cx.waker().wake_by_ref();
+                break;
+            }
+            budget -= 1;
+            match this.inner.as_mut().poll_next(cx) {
+                Poll::Pending => break,
+                Poll::Ready(None) => {
+                    *this.terminated = true;
+                    break;
+                }
+                Poll::Ready(Some(item)) => fresh = Some(item),
+            }
+        }
+
+        if let Some(item) = fresh {
+            *this.last = Some(item.clone());
+            return Poll::Ready(Some(item));
+        }
+        if *this.terminated {
+            *this.last = None;
+            return Poll::Ready(None);
+        }
+        match this.last {
+            None => Poll::Pending,
+            Some(last) => Poll::Ready(Some(last.clone())),
+        }
+    }
+}
+
+impl<S> ResampleLatestStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+    use crate::test_utils::ready_after_n_polls;
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<()>()
+            .resample_latest()
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn a_burst_ended_by_the_upstream_yields_its_last_item() {
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .resample_latest()
+                .collect::<Vec<_>>()
+                .await,
+            vec![3]
+        );
+    }
+
+    #[tokio::test]
+    async fn bursts_are_collapsed_and_the_latest_is_repeated() {
+        assert_eq!(
+            stream::iter([vec![1, 2, 3], vec![4, 5], vec![6]])
+                .map(stream::iter)
+                .then(|chunk| ready_after_n_polls(chunk, 2))
+                .flatten()
+                .chain(stream::pending())
+                .resample_latest()
+                .take(7)
+                .collect::<Vec<_>>()
+                .await,
+            vec![3, 3, 5, 5, 6, 6, 6]
+        );
+    }
+
+    #[tokio::test]
+    async fn an_always_ready_upstream_is_drained_in_bounded_steps() {
+        let budget = drain::BUDGET as u32;
+        assert_eq!(
+            stream::iter(0..)
+                .resample_latest()
+                .take(2)
+                .collect::<Vec<_>>()
+                .await,
+            vec![budget - 1, 2 * budget - 1]
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+use crate::{sync_utils::lock, with_high_water::Buffered};
+
+pub trait RingStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: Clone,
+{
+    /// Pass the items through, keeping a copy of the last `n` of them available via [`RingHandle::window`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    fn ring(self, n: usize) -> (Ring<Self>, RingHandle<Self::Item>) {
+        Ring::new(self, n)
+    }
+}
+
+/// Stream for [`ring`](`RingStreamExt::ring`) method.
+#[derive(Debug)]
+#[pin_project::pin_project]
+pub struct Ring<S: Stream> {
+    #[pin]
+    inner: S,
+    n: usize,
+
+    window: Arc<Mutex<VecDeque<S::Item>>>,
+}
+
+/// Read access to the window of a [`Ring`].
+#[derive(Debug, Clone)]
+pub struct RingHandle<Item> {
+    window: Arc<Mutex<VecDeque<Item>>>,
+}
+
+impl<S: Stream> Ring<S> {
+    pub fn new(inner: S, n: usize) -> (Self, RingHandle<S::Item>) {
+        assert!(n > 0, "the ring must not be empty");
+
+        let window = Arc::new(Mutex::new(VecDeque::with_capacity(n)));
+        let handle = RingHandle {
+            window: window.clone(),
+        };
+        (Self { inner, n, window }, handle)
+    }
+}
+
+impl<Item> RingHandle<Item>
+where
+    Item: Clone,
+{
+    /// A copy of the last items passed through the ring, oldest first.
+    pub fn window(&self) -> Vec<Item> {
+        lock(&self.window).iter().cloned().collect()
+    }
+}
+
+impl<S> Stream for Ring<S>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        let Some(item) = ready!(this.inner.poll_next(cx)) else {
+            return Poll::Ready(None);
+        };
+
+        let mut window = lock(this.window);
+        if window.len() == *this.n {
+            window.pop_front();
+        }
+        window.push_back(item.clone());
+
+        Poll::Ready(Some(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S: Stream> Buffered for Ring<S> {
+    /// Number of items in the window.
+    fn buffered_len(&self) -> usize {
+        lock(&self.window).len()
+    }
+}
+
+impl<S> RingStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn items_pass_through() {
+        let (ring, _) = stream::iter([1, 2, 3]).ring(2);
+        assert_eq!(ring.collect::<Vec<_>>().await, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn the_window_follows_the_stream() {
+        let (mut ring, handle) = stream::iter(1..=5).ring(3);
+        assert!(handle.window().is_empty());
+
+        assert_eq!(ring.next().await, Some(1));
+        assert_eq!(handle.window(), vec![1]);
+
+        assert_eq!(ring.next().await, Some(2));
+        assert_eq!(ring.next().await, Some(3));
+        assert_eq!(handle.window(), vec![1, 2, 3]);
+
+        assert_eq!(ring.next().await, Some(4));
+        assert_eq!(handle.clone().window(), vec![2, 3, 4]);
+
+        assert_eq!(ring.next().await, Some(5));
+        assert_eq!(ring.next().await, None);
+        assert_eq!(handle.window(), vec![3, 4, 5]);
+    }
+}
@@ -0,0 +1,160 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+use crate::zip_biased::ZipBiased;
+
+pub trait RollingCorrelationStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: Into<f64>,
+{
+    /// Emit the Pearson correlation coefficient of the last `n` pairs of items of both streams.
+    ///
+    /// The items are paired as by [`zip_biased`](`crate::zip_biased::ZipBiasedStreamExt::zip_biased`).
+    /// Nothing is emitted until the window is full. If either side has zero variance within the window,
+    /// the coefficient is undefined and `NaN` is emitted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    fn rolling_correlation<R>(self, right: R, n: usize) -> RollingCorrelation<Self, R, Self::Item>
+    where
+        R: Stream,
+        R::Item: Into<f64>,
+    {
+        RollingCorrelation::new(self, right, n)
+    }
+}
+
+/// Stream for [`rolling_correlation`](`RollingCorrelationStreamExt::rolling_correlation`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct RollingCorrelation<L, R, LI> {
+    #[pin]
+    inner: ZipBiased<L, R, LI>,
+    n: usize,
+
+    window: VecDeque<(f64, f64)>,
+}
+
+impl<L, R, LI> RollingCorrelation<L, R, LI> {
+    pub fn new(left: L, right: R, n: usize) -> Self {
+        assert!(n > 0, "the window must not be empty");
+        Self {
+            inner: ZipBiased::new(left, right),
+            n,
+            window: VecDeque::with_capacity(n),
+        }
+    }
+}
+
+impl<L, R> Stream for RollingCorrelation<L, R, L::Item>
+where
+    L: Stream,
+    R: Stream,
+    L::Item: Into<f64>,
+    R::Item: Into<f64>,
+{
+    type Item = f64;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            let Some((x, y)) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                break None;
+            };
+
+            if this.window.len() == *this.n {
+                this.window.pop_front();
+            }
+            this.window.push_back((x.into(), y.into()));
+            if this.window.len() < *this.n {
+                continue;
+            }
+
+            let len = this.window.len() as f64;
+            let (sum_x, sum_y) = this
+                .window
+                .iter()
+                .fold((0.0, 0.0), |(sx, sy), (x, y)| (sx + x, sy + y));
+            let (mean_x, mean_y) = (sum_x / len, sum_y / len);
+            let (cov, var_x, var_y) =
+                this.window
+                    .iter()
+                    .fold((0.0, 0.0, 0.0), |(cov, vx, vy), (x, y)| {
+                        let (dx, dy) = (x - mean_x, y - mean_y);
+                        (cov + dx * dy, vx + dx * dx, vy + dy * dy)
+                    });
+
+            break Some(if var_x == 0.0 || var_y == 0.0 {
+                f64::NAN
+            } else {
+                cov / (var_x * var_y).sqrt()
+            });
+        })
+    }
+}
+
+impl<S> RollingCorrelationStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Into<f64>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn nothing_is_emitted_until_the_window_is_full() {
+        assert!(stream::iter([1.0, 2.0])
+            .rolling_correlation(stream::iter([1.0, 2.0]), 3)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn correlated_sequences() {
+        let coefficients = stream::iter([1.0, 2.0, 4.0, 3.0, 5.0])
+            .rolling_correlation(stream::iter([10u8, 19, 41, 33, 52]), 3)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(coefficients.len(), 3);
+        assert!(coefficients.iter().all(|c| *c > 0.9));
+    }
+
+    #[tokio::test]
+    async fn anti_correlated_sequences() {
+        let coefficients = stream::iter([1.0, 2.0, 3.0, 4.0])
+            .rolling_correlation(stream::iter([8.0, 6.0, 4.0, 2.0]), 2)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(coefficients.len(), 3);
+        assert!(coefficients.iter().all(|c| (c + 1.0).abs() < 1e-12));
+    }
+
+    #[tokio::test]
+    async fn zero_variance_yields_nan() {
+        let coefficients = stream::iter([1.0, 2.0, 3.0])
+            .rolling_correlation(stream::iter([5.0; 3]), 3)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(coefficients.len(), 1);
+        assert!(coefficients[0].is_nan());
+    }
+}
@@ -0,0 +1,172 @@
+use std::{
+    collections::VecDeque,
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+use num_traits::{CheckedAdd, CheckedSub};
+
+pub trait RollingSumCheckedStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: CheckedAdd + CheckedSub + Clone,
+{
+    /// Emit the sum of the last `n` items, updated incrementally with checked arithmetic.
+    ///
+    /// The sums of the partial windows are emitted from the first item on. Each update first subtracts
+    /// the item leaving the window and then adds the new one; if either step overflows,
+    /// [`Overflow`] is yielded and the stream ends.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    fn rolling_sum_checked(self, n: usize) -> RollingSumChecked<Self, Self::Item> {
+        RollingSumChecked::new(self, n)
+    }
+}
+
+/// The rolling sum of [`RollingSumChecked`] does not fit into the item type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("rolling sum overflow")
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Stream for [`rolling_sum_checked`](`RollingSumCheckedStreamExt::rolling_sum_checked`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct RollingSumChecked<Stream, Item> {
+    #[pin]
+    inner: Stream,
+    n: usize,
+
+    window: VecDeque<Item>,
+    sum: Option<Item>,
+    terminated: bool,
+}
+
+impl<S> RollingSumChecked<S, S::Item>
+where
+    S: Stream,
+{
+    pub fn new(inner: S, n: usize) -> Self {
+        assert!(n > 0, "the window must not be empty");
+        Self {
+            inner,
+            n,
+            window: VecDeque::with_capacity(n),
+            sum: None,
+            terminated: false,
+        }
+    }
+}
+
+impl<S> Stream for RollingSumChecked<S, S::Item>
+where
+    S: Stream,
+    S::Item: CheckedAdd + CheckedSub + Clone,
+{
+    type Item = Result<S::Item, Overflow>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        if *this.terminated {
+            return Poll::Ready(None);
+        }
+
+        let Some(x) = ready!(this.inner.poll_next(cx)) else {
+            *this.terminated = true;
+            return Poll::Ready(None);
+        };
+
+        let evicted = if this.window.len() == *this.n {
+            this.window.pop_front()
+        } else {
+            None
+        };
+
+        let sum = match (this.sum.take(), evicted) {
+            (None, _) => Some(x.clone()),
+            (Some(sum), None) => sum.checked_add(&x),
+            (Some(sum), Some(old)) => sum.checked_sub(&old).and_then(|s| s.checked_add(&x)),
+        };
+        let Some(sum) = sum else {
+            *this.terminated = true;
+            this.window.clear();
+            return Poll::Ready(Some(Err(Overflow)));
+        };
+
+        this.window.push_back(x);
+        *this.sum = Some(sum.clone());
+
+        Poll::Ready(Some(Ok(sum)))
+    }
+}
+
+impl<S> RollingSumCheckedStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: CheckedAdd + CheckedSub + Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn partial_windows_are_summed_too() {
+        assert_eq!(
+            stream::iter([1, 2, 3, 4, 5])
+                .rolling_sum_checked(3)
+                .collect::<Vec<_>>()
+                .await,
+            vec![Ok(1), Ok(3), Ok(6), Ok(9), Ok(12)]
+        );
+    }
+
+    #[tokio::test]
+    async fn a_sliding_window_stays_in_range() {
+        assert_eq!(
+            stream::iter([200u8, 50, 150, 100])
+                .rolling_sum_checked(2)
+                .collect::<Vec<_>>()
+                .await,
+            vec![Ok(200), Ok(250), Ok(200), Ok(250)]
+        );
+    }
+
+    #[tokio::test]
+    async fn overflow_is_surfaced_and_ends_the_stream() {
+        assert_eq!(
+            stream::iter([100u8, 100, 100, 1])
+                .rolling_sum_checked(3)
+                .collect::<Vec<_>>()
+                .await,
+            vec![Ok(100), Ok(200), Err(Overflow)]
+        );
+    }
+
+    #[tokio::test]
+    async fn signed_underflow_is_an_overflow_too() {
+        assert_eq!(
+            stream::iter([i8::MIN, -1])
+                .rolling_sum_checked(2)
+                .collect::<Vec<_>>()
+                .await,
+            vec![Ok(i8::MIN), Err(Overflow)]
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait RollingZscoreStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: Into<f64>,
+{
+    /// Normalize each value against the mean and the (population) standard deviation of the last `n` values.
+    ///
+    /// Nothing is emitted until the window is full. A window with zero variance yields `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    fn rolling_zscore(self, n: usize) -> RollingZscore<Self> {
+        RollingZscore::new(self, n)
+    }
+}
+
+/// Stream for [`rolling_zscore`](`RollingZscoreStreamExt::rolling_zscore`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct RollingZscore<Stream> {
+    #[pin]
+    inner: Stream,
+    n: usize,
+
+    window: VecDeque<f64>,
+}
+
+impl<S> RollingZscore<S> {
+    pub fn new(inner: S, n: usize) -> Self {
+        assert!(n > 0, "the window must not be empty");
+        Self {
+            inner,
+            n,
+            window: VecDeque::with_capacity(n),
+        }
+    }
+}
+
+impl<S> Stream for RollingZscore<S>
+where
+    S: Stream,
+    S::Item: Into<f64>,
+{
+    type Item = f64;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            let Some(x) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                break None;
+            };
+            let x = x.into();
+
+            if this.window.len() == *this.n {
+                this.window.pop_front();
+            }
+            this.window.push_back(x);
+            if this.window.len() < *this.n {
+                continue;
+            }
+
+            let len = this.window.len() as f64;
+            let mean = this.window.iter().sum::<f64>() / len;
+            let variance = this.window.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / len;
+
+            break Some(if variance == 0.0 {
+                0.0
+            } else {
+                (x - mean) / variance.sqrt()
+            });
+        })
+    }
+}
+
+impl<S> RollingZscoreStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Into<f64>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn nothing_is_emitted_until_the_window_is_full() {
+        assert!(stream::iter([1.0, 2.0])
+            .rolling_zscore(3)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn zscores_of_a_known_sequence() {
+        let zscores = stream::iter([1u8, 2, 3, 3, 3])
+            .rolling_zscore(3)
+            .collect::<Vec<_>>()
+            .await;
+        let expected = [1.5f64.sqrt(), 0.5f64.sqrt(), 0.0];
+
+        assert_eq!(zscores.len(), expected.len());
+        for (actual, expected) in zscores.into_iter().zip(expected) {
+            assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+        }
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn empty_window_panics() {
+        let _ = stream::iter([1.0]).rolling_zscore(0);
+    }
+}
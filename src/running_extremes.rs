@@ -0,0 +1,98 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait RunningExtremesStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: Ord + Clone,
+{
+    /// Tag each item with the minimum and the maximum seen so far: `(current, running_min, running_max)`.
+    fn running_extremes(self) -> RunningExtremes<Self, Self::Item> {
+        RunningExtremes::new(self)
+    }
+}
+
+/// Stream for [`running_extremes`](`RunningExtremesStreamExt::running_extremes`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct RunningExtremes<Stream, Item> {
+    #[pin]
+    inner: Stream,
+
+    extremes: Option<(Item, Item)>,
+}
+
+impl<S> RunningExtremes<S, S::Item>
+where
+    S: Stream,
+    S::Item: Ord + Clone,
+{
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            extremes: None,
+        }
+    }
+}
+
+impl<S> Stream for RunningExtremes<S, S::Item>
+where
+    S: Stream,
+    S::Item: Ord + Clone,
+{
+    type Item = (S::Item, S::Item, S::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        let item = ready!(this.inner.poll_next(cx));
+        Poll::Ready(item.map(|item| {
+            let (min, max) = match this.extremes.take() {
+                None => (item.clone(), item.clone()),
+                Some((min, max)) => (min.min(item.clone()), max.max(item.clone())),
+            };
+            *this.extremes = Some((min.clone(), max.clone()));
+            (item, min, max)
+        }))
+    }
+}
+
+impl<S> RunningExtremesStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Ord + Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<i32>()
+            .running_extremes()
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn extremes_are_updated_per_item() {
+        assert_eq!(
+            stream::iter([3, 1, 4, 1, 5])
+                .running_extremes()
+                .collect::<Vec<_>>()
+                .await,
+            vec![(3, 3, 3), (1, 1, 3), (4, 1, 4), (1, 1, 4), (5, 1, 5)]
+        );
+    }
+}
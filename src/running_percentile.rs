@@ -0,0 +1,148 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait RunningPercentileStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: Into<f64>,
+{
+    /// Emit the `p`-th percentile of the last `window` values.
+    ///
+    /// The window is kept sorted, and the percentile is interpolated linearly between the closest ranks,
+    /// so `p == 0.5` is the median and `p == 1.0` is the maximum. Nothing is emitted until the window is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not within `[0.0, 1.0]` or if `window` is zero.
+    fn running_percentile(self, p: f64, window: usize) -> RunningPercentile<Self> {
+        RunningPercentile::new(self, p, window)
+    }
+}
+
+/// Stream for [`running_percentile`](`RunningPercentileStreamExt::running_percentile`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct RunningPercentile<Stream> {
+    #[pin]
+    inner: Stream,
+    p: f64,
+    window: usize,
+
+    arrivals: VecDeque<f64>,
+    sorted: Vec<f64>,
+}
+
+impl<S> RunningPercentile<S> {
+    pub fn new(inner: S, p: f64, window: usize) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&p),
+            "the percentile must be within [0.0, 1.0]"
+        );
+        assert!(window > 0, "the window must not be empty");
+        Self {
+            inner,
+            p,
+            window,
+            arrivals: VecDeque::with_capacity(window),
+            sorted: Vec::with_capacity(window),
+        }
+    }
+}
+
+impl<S> Stream for RunningPercentile<S>
+where
+    S: Stream,
+    S::Item: Into<f64>,
+{
+    type Item = f64;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            let Some(x) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                break None;
+            };
+            let x = x.into();
+
+            if this.arrivals.len() == *this.window {
+                let old = this.arrivals.pop_front().expect("the window is full");
+                let at = this.sorted.partition_point(|v| v.total_cmp(&old).is_lt());
+                this.sorted.remove(at);
+            }
+            this.arrivals.push_back(x);
+            let at = this.sorted.partition_point(|v| v.total_cmp(&x).is_lt());
+            this.sorted.insert(at, x);
+
+            if this.sorted.len() < *this.window {
+                continue;
+            }
+
+            let rank = *this.p * (this.sorted.len() - 1) as f64;
+            let (lo, hi) = (rank.floor() as usize, rank.ceil() as usize);
+            let (lo_v, hi_v) = (this.sorted[lo], this.sorted[hi]);
+            break Some(lo_v + (hi_v - lo_v) * (rank - lo as f64));
+        })
+    }
+}
+
+impl<S> RunningPercentileStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Into<f64>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn nothing_is_emitted_until_the_window_is_full() {
+        assert!(stream::iter([1.0, 2.0])
+            .running_percentile(0.5, 3)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn running_median() {
+        assert_eq!(
+            stream::iter([5u8, 1, 4, 2, 3, 9, 9])
+                .running_percentile(0.5, 3)
+                .collect::<Vec<_>>()
+                .await,
+            vec![4.0, 2.0, 3.0, 3.0, 9.0]
+        );
+    }
+
+    #[tokio::test]
+    async fn p95_of_a_uniform_distribution() {
+        let values = (0..2000u32)
+            .map(|i| f64::from(i * 7919 % 1000))
+            .collect::<Vec<_>>();
+        let p95 = stream::iter(values)
+            .running_percentile(0.95, 1000)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(p95.len(), 1001);
+        assert!(p95.iter().all(|p| (p - 949.05).abs() < 1e-9));
+    }
+
+    #[test]
+    #[should_panic]
+    fn percentile_out_of_range() {
+        let _ = stream::empty::<f64>().running_percentile(1.5, 10);
+    }
+}
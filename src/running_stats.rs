@@ -0,0 +1,133 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait RunningStatsStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: Into<f64>,
+{
+    /// Yield the running `(count, mean, variance)` of the items seen so far.
+    ///
+    /// The statistics are updated with Welford's online algorithm; the variance is the population one,
+    /// so the first item yields `0.0`.
+    fn running_stats(self) -> RunningStats<Self> {
+        RunningStats::new(self)
+    }
+}
+
+/// Stream for [`running_stats`](`RunningStatsStreamExt::running_stats`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct RunningStats<Stream> {
+    #[pin]
+    inner: Stream,
+
+    count: f64,
+    mean: f64,
+    m2: f64,
+}
+
+impl<S> RunningStats<S> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            count: 0.0,
+            mean: 0.0,
+            m2: 0.0,
+        }
+    }
+}
+
+impl<S> Stream for RunningStats<S>
+where
+    S: Stream,
+    S::Item: Into<f64>,
+{
+    type Item = (f64, f64, f64);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        let item = ready!(this.inner.poll_next(cx));
+        Poll::Ready(item.map(|item| {
+            let value = item.into();
+            *this.count += 1.0;
+            let delta = value - *this.mean;
+            *this.mean += delta / *this.count;
+            *this.m2 += delta * (value - *this.mean);
+            (*this.count, *this.mean, *this.m2 / *this.count)
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S> RunningStatsStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Into<f64>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    fn reference(values: &[f64]) -> (f64, f64, f64) {
+        let count = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / count;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / count;
+        (count, mean, variance)
+    }
+
+    #[tokio::test]
+    async fn matches_the_two_pass_reference() {
+        let values = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let stats = stream::iter(values)
+            .running_stats()
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(stats.len(), values.len());
+        for (n, (count, mean, variance)) in stats.into_iter().enumerate() {
+            let (ref_count, ref_mean, ref_variance) = reference(&values[..=n]);
+            assert_eq!(count, ref_count);
+            assert!((mean - ref_mean).abs() < 1e-12);
+            assert!((variance - ref_variance).abs() < 1e-12);
+        }
+    }
+
+    #[tokio::test]
+    async fn the_first_item_has_no_variance() {
+        assert_eq!(
+            stream::iter([3u8, 5])
+                .running_stats()
+                .collect::<Vec<_>>()
+                .await,
+            vec![(1.0, 3.0, 0.0), (2.0, 4.0, 1.0)]
+        );
+    }
+
+    #[tokio::test]
+    async fn large_offsets_do_not_cancel_out() {
+        let (_, mean, variance) = stream::iter([1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0])
+            .running_stats()
+            .collect::<Vec<_>>()
+            .await
+            .pop()
+            .unwrap();
+
+        assert!((mean - (1e9 + 10.0)).abs() < 1e-6);
+        assert!((variance - 22.5).abs() < 1e-6);
+    }
+}
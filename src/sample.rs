@@ -0,0 +1,183 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{stream::FusedStream, Stream};
+
+use crate::drain;
+
+pub trait SampleStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: Clone,
+{
+    /// Yield the most recent item each time `trigger` yields.
+    ///
+    /// The upstream is drained eagerly, a bounded number of items per poll. The latest item is retained
+    /// after being sampled, so it is yielded again on the following triggers unless a fresher one arrives,
+    /// including after the upstream has ended. Triggers that fire before the first item are ignored.
+    /// The stream ends when `trigger` ends.
+    fn sample<T>(self, trigger: T) -> Sample<Self, T, Self::Item>
+    where
+        T: Stream,
+    {
+        Sample::new(self, trigger)
+    }
+}
+
+/// Stream for [`sample`](`SampleStreamExt::sample`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct Sample<Stream, Trigger, Item> {
+    #[pin]
+    inner: Stream,
+    #[pin]
+    trigger: Trigger,
+
+    latest: Option<Item>,
+    inner_done: bool,
+    terminated: bool,
+}
+
+impl<S, T, I> Sample<S, T, I> {
+    pub fn new(inner: S, trigger: T) -> Self {
+        Self {
+            inner,
+            trigger,
+            latest: None,
+            inner_done: false,
+            terminated: false,
+        }
+    }
+}
+
+impl<S, T> Stream for Sample<S, T, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+    T: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        if *this.terminated {
+            return Poll::Ready(None);
+        }
+
+        let mut budget = drain::BUDGET;
+        while !*this.inner_done {
+            if budget == 0 {
+                cx.waker().wake_by_ref();
+                break;
+            }
+            budget -= 1;
+            match this.inner.as_mut().poll_next(cx) {
+                Poll::Pending => break,
+                Poll::Ready(None) => *this.inner_done = true,
+                Poll::Ready(Some(item)) => *this.latest = Some(item),
+            }
+        }
+
+        Poll::Ready(loop {
+            if ready!(this.trigger.as_mut().poll_next(cx)).is_none() {
+                *this.terminated = true;
+                *this.latest = None;
+                break None;
+            }
+            if let Some(latest) = this.latest.as_ref() {
+                break Some(latest.clone());
+            }
+        })
+    }
+}
+
+impl<S, T> FusedStream for Sample<S, T, S::Item>
+where
+    S: Stream,
+    S::Item: Clone,
+    T: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<S> SampleStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{channel::mpsc, poll, stream, StreamExt};
+
+    use super::*;
+    use crate::test_utils::ready_after_n_polls;
+
+    #[tokio::test]
+    async fn only_the_sampled_items_appear() {
+        let source = stream::iter([[1, 2, 3], [4, 5, 6], [7, 8, 9]])
+            .map(stream::iter)
+            .then(|chunk| ready_after_n_polls(chunk, 1))
+            .flatten();
+        let (tick, ticks) = mpsc::unbounded();
+        let mut sampled = source.sample(ticks);
+
+        assert_eq!(poll!(sampled.next()), Poll::Pending);
+        tick.unbounded_send(()).unwrap();
+        assert_eq!(poll!(sampled.next()), Poll::Ready(Some(3)));
+
+        assert_eq!(poll!(sampled.next()), Poll::Pending);
+        tick.unbounded_send(()).unwrap();
+        assert_eq!(poll!(sampled.next()), Poll::Ready(Some(9)));
+    }
+
+    #[tokio::test]
+    async fn the_latest_item_is_retained() {
+        let (tick, ticks) = mpsc::unbounded();
+        let mut sampled = stream::iter([1, 2]).sample(ticks);
+
+        tick.unbounded_send(()).unwrap();
+        tick.unbounded_send(()).unwrap();
+        assert_eq!(poll!(sampled.next()), Poll::Ready(Some(2)));
+        assert_eq!(poll!(sampled.next()), Poll::Ready(Some(2)));
+        assert_eq!(poll!(sampled.next()), Poll::Pending);
+
+        drop(tick);
+        assert_eq!(poll!(sampled.next()), Poll::Ready(None));
+        assert!(sampled.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn triggers_before_the_first_item_are_ignored() {
+        let (tick, ticks) = mpsc::unbounded();
+        let (data, source) = mpsc::unbounded();
+        let mut sampled = source.sample(ticks);
+
+        tick.unbounded_send(()).unwrap();
+        assert_eq!(poll!(sampled.next()), Poll::Pending);
+
+        data.unbounded_send('a').unwrap();
+        assert_eq!(poll!(sampled.next()), Poll::Pending);
+        tick.unbounded_send(()).unwrap();
+        assert_eq!(poll!(sampled.next()), Poll::Ready(Some('a')));
+    }
+
+    #[tokio::test]
+    async fn an_always_ready_upstream_does_not_starve_the_trigger() {
+        let sampled = stream::iter(0..)
+            .sample(stream::iter([(), (), ()]))
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(sampled.len(), 3);
+        assert!(sampled.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -0,0 +1,153 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+use crate::drain;
+
+pub trait SessionizePollsStreamExt: Stream + Sized {
+    /// Group the items into sessions, ending a session once the upstream has been pending for `gap` polls in a row.
+    ///
+    /// While a session is open, the stream wakes itself up so that the pending polls keep coming.
+    /// The upstream is drained a bounded number of items per poll, which does not count as a pending poll.
+    /// The last session is flushed when the upstream ends.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `gap` is zero.
+    fn sessionize_polls(self, gap: usize) -> SessionizePolls<Self, Self::Item> {
+        SessionizePolls::new(self, gap)
+    }
+}
+
+/// Stream for [`sessionize_polls`](`SessionizePollsStreamExt::sessionize_polls`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct SessionizePolls<Stream, Item> {
+    #[pin]
+    inner: Stream,
+    gap: usize,
+
+    session: Vec<Item>,
+    idle_polls: usize,
+    terminated: bool,
+}
+
+impl<S> SessionizePolls<S, S::Item>
+where
+    S: Stream,
+{
+    pub fn new(inner: S, gap: usize) -> Self {
+        assert!(gap > 0, "the gap must not be zero");
+        Self {
+            inner,
+            gap,
+            session: Vec::new(),
+            idle_polls: 0,
+            terminated: false,
+        }
+    }
+}
+
+impl<S> Stream for SessionizePolls<S, S::Item>
+where
+    S: Stream,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        let mut budget = drain::BUDGET;
+        loop {
+            if budget == 0 {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            budget -= 1;
+
+            if *this.terminated {
+                let session = std::mem::take(this.session);
+                return Poll::Ready(Some(session).filter(|s| !s.is_empty()));
+            }
+
+            match this.inner.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    this.session.push(item);
+                    *this.idle_polls = 0;
+                }
+                Poll::Ready(None) => *this.terminated = true,
+                Poll::Pending if this.session.is_empty() => return Poll::Pending,
+                Poll::Pending => {
+                    *this.idle_polls += 1;
+                    if *this.idle_polls < *this.gap {
+                        cx.waker().wake_by_ref();
+                        return Poll::Pending;
+                    }
+                    *this.idle_polls = 0;
+                    return Poll::Ready(Some(std::mem::take(this.session)));
+                }
+            }
+        }
+    }
+}
+
+impl<S> SessionizePollsStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+    use crate::test_utils::ready_after_n_polls;
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<()>()
+            .sessionize_polls(2)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn gaps_of_at_least_gap_polls_end_the_session() {
+        assert_eq!(
+            stream::iter([(1, 0), (2, 1), (3, 3), (4, 2), (5, 0), (6, 5)])
+                .then(|(item, polls)| ready_after_n_polls(item, polls))
+                .sessionize_polls(2)
+                .collect::<Vec<_>>()
+                .await,
+            vec![vec![1, 2], vec![3], vec![4, 5], vec![6]]
+        );
+    }
+
+    #[tokio::test]
+    async fn the_last_session_is_flushed_at_the_end() {
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .sessionize_polls(1)
+                .collect::<Vec<_>>()
+                .await,
+            vec![vec![1, 2, 3]]
+        );
+    }
+
+    #[tokio::test]
+    async fn an_always_ready_upstream_is_drained_in_bounded_steps() {
+        use futures::poll;
+
+        let mut sessions = stream::repeat(1).sessionize_polls(1);
+        assert_eq!(poll!(sessions.next()), Poll::Pending);
+
+        assert_eq!(
+            stream::iter(0..100)
+                .sessionize_polls(1)
+                .collect::<Vec<_>>()
+                .await,
+            vec![(0..100).collect::<Vec<_>>()]
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use std::{
+    collections::VecDeque,
+    ops::Sub,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait SettleWithinStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: PartialOrd + Sub<Output = Self::Item> + Clone,
+{
+    /// Wait until `count` consecutive items stay within `epsilon` of each other, then emit the latest one and end.
+    ///
+    /// If the upstream ends before settling, the stream ends as per `unsettled`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is zero.
+    fn settle_within(
+        self,
+        epsilon: Self::Item,
+        count: usize,
+        unsettled: Unsettled,
+    ) -> SettleWithin<Self, Self::Item> {
+        SettleWithin::new(self, epsilon, count, unsettled)
+    }
+}
+
+/// What [`SettleWithin`] does if the upstream ends before settling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unsettled {
+    /// End without emitting anything.
+    Discard,
+    /// Emit the last upstream item, if there was any.
+    EmitLast,
+}
+
+/// Stream for [`settle_within`](`SettleWithinStreamExt::settle_within`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct SettleWithin<Stream, Item> {
+    #[pin]
+    inner: Stream,
+    epsilon: Item,
+    count: usize,
+    unsettled: Unsettled,
+
+    window: VecDeque<Item>,
+    done: bool,
+}
+
+impl<S> SettleWithin<S, S::Item>
+where
+    S: Stream,
+    S::Item: PartialOrd + Sub<Output = S::Item> + Clone,
+{
+    pub fn new(inner: S, epsilon: S::Item, count: usize, unsettled: Unsettled) -> Self {
+        assert!(count > 0, "the count must not be zero");
+        Self {
+            inner,
+            epsilon,
+            count,
+            unsettled,
+            window: VecDeque::with_capacity(count),
+            done: false,
+        }
+    }
+}
+
+impl<S> Stream for SettleWithin<S, S::Item>
+where
+    S: Stream,
+    S::Item: PartialOrd + Sub<Output = S::Item> + Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        Poll::Ready(loop {
+            let Some(item) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                *this.done = true;
+                let last = this.window.pop_back();
+                this.window.clear();
+                break last.filter(|_| *this.unsettled == Unsettled::EmitLast);
+            };
+
+            if this.window.len() == *this.count {
+                this.window.pop_front();
+            }
+            this.window.push_back(item);
+            if this.window.len() < *this.count {
+                continue;
+            }
+
+            let mut values = this.window.iter();
+            let first = values.next().expect("the window is not empty");
+            let (min, max) = values.fold((first, first), |(min, max), v| {
+                (if v < min { v } else { min }, if v > max { v } else { max })
+            });
+            if max.clone() - min.clone() <= *this.epsilon {
+                *this.done = true;
+                let settled = this.window.pop_back();
+                this.window.clear();
+                break settled;
+            }
+        })
+    }
+}
+
+impl<S> SettleWithinStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: PartialOrd + Sub<Output = S::Item> + Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    const OSCILLATING: [f64; 9] = [10.0, 0.0, 8.0, 3.0, 5.0, 5.2, 4.9, 5.1, 7.0];
+
+    #[tokio::test]
+    async fn emits_the_settled_value_and_ends() {
+        assert_eq!(
+            stream::iter(OSCILLATING)
+                .settle_within(0.5, 3, Unsettled::Discard)
+                .collect::<Vec<_>>()
+                .await,
+            vec![4.9]
+        );
+    }
+
+    #[tokio::test]
+    async fn unsettled_stream_is_discarded() {
+        assert!(stream::iter(OSCILLATING)
+            .settle_within(0.1, 3, Unsettled::Discard)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn unsettled_stream_emits_the_last_value() {
+        assert_eq!(
+            stream::iter(OSCILLATING)
+                .settle_within(0.1, 3, Unsettled::EmitLast)
+                .collect::<Vec<_>>()
+                .await,
+            vec![7.0]
+        );
+    }
+
+    #[tokio::test]
+    async fn integers_settle_too() {
+        assert_eq!(
+            stream::iter([1, 9, 4, 4, 5, 4, 4])
+                .settle_within(0, 2, Unsettled::Discard)
+                .collect::<Vec<_>>()
+                .await,
+            vec![4]
+        );
+    }
+}
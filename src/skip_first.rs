@@ -0,0 +1,145 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{stream::FusedStream, Stream};
+
+pub trait SkipFirstStreamExt: Stream + Sized {
+    /// Drop the first item and forward the rest.
+    ///
+    /// Unlike [`skip`](`futures::StreamExt::skip`), the stream is fused regardless of the upstream:
+    /// once the upstream has ended, it is not polled again.
+    fn skip_first(self) -> SkipFirst<Self> {
+        SkipFirst::new(self)
+    }
+}
+
+/// Stream for [`skip_first`](`SkipFirstStreamExt::skip_first`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct SkipFirst<Stream> {
+    #[pin]
+    inner: Stream,
+
+    skipped: bool,
+    terminated: bool,
+}
+
+impl<S> SkipFirst<S> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            skipped: false,
+            terminated: false,
+        }
+    }
+}
+
+impl<S> Stream for SkipFirst<S>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        if *this.terminated {
+            return Poll::Ready(None);
+        }
+
+        Poll::Ready(loop {
+            let Some(item) = ready!(this.inner.as_mut().poll_next(cx)) else {
+                *this.terminated = true;
+                break None;
+            };
+            if *this.skipped {
+                break Some(item);
+            }
+            *this.skipped = true;
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.terminated {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.inner.size_hint();
+        if self.skipped {
+            (lower, upper)
+        } else {
+            (lower.saturating_sub(1), upper.map(|u| u.saturating_sub(1)))
+        }
+    }
+}
+
+impl<S> FusedStream for SkipFirst<S>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<S> SkipFirstStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn empty_stream() {
+        assert!(stream::empty::<()>()
+            .skip_first()
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn the_first_item_is_dropped() {
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .skip_first()
+                .collect::<Vec<_>>()
+                .await,
+            vec![2, 3]
+        );
+    }
+
+    #[tokio::test]
+    async fn size_hint_accounts_for_the_skipped_item() {
+        let mut skipping = stream::iter([1, 2, 3]).skip_first();
+        assert_eq!(skipping.size_hint(), (2, Some(2)));
+
+        assert_eq!(skipping.next().await, Some(2));
+        assert_eq!(skipping.size_hint(), (1, Some(1)));
+
+        assert_eq!(stream::empty::<()>().skip_first().size_hint(), (0, Some(0)));
+    }
+
+    #[tokio::test]
+    async fn the_upstream_is_not_polled_after_the_end() {
+        let polls = AtomicUsize::new(0);
+        let mut skipping = stream::poll_fn(|_| {
+            polls.fetch_add(1, Ordering::Relaxed);
+            Poll::Ready(None::<()>)
+        })
+        .skip_first();
+
+        assert!(!skipping.is_terminated());
+        assert_eq!(skipping.next().await, None);
+        assert!(skipping.is_terminated());
+        assert_eq!(skipping.next().await, None);
+        assert_eq!(skipping.size_hint(), (0, Some(0)));
+        assert_eq!(polls.load(Ordering::Relaxed), 1);
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+use crate::interleave_snapshots::Mixed;
+
+/// An upstream item or a snapshot requested by the control stream of [`SnapshotOn`].
+pub type WithSnapshot<T, S> = Mixed<T, S>;
+
+pub trait SnapshotOnStreamExt: Stream + Sized {
+    /// Pass the items through, emitting `snapshot()` each time `control` yields.
+    ///
+    /// The control stream is polled first, so a requested snapshot precedes the items ready at the same time.
+    /// The stream ends with the upstream; the end of `control` only stops the snapshots.
+    fn snapshot_on<C, F, S>(self, control: C, snapshot: F) -> SnapshotOn<Self, C, F>
+    where
+        C: Stream,
+        F: FnMut() -> S,
+    {
+        SnapshotOn::new(self, control, snapshot)
+    }
+}
+
+/// Stream for [`snapshot_on`](`SnapshotOnStreamExt::snapshot_on`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct SnapshotOn<Stream, C, F> {
+    #[pin]
+    inner: Stream,
+    #[pin]
+    control: C,
+    snapshot: F,
+
+    control_done: bool,
+}
+
+impl<S, C, F> SnapshotOn<S, C, F> {
+    pub fn new(inner: S, control: C, snapshot: F) -> Self {
+        Self {
+            inner,
+            control,
+            snapshot,
+            control_done: false,
+        }
+    }
+}
+
+impl<S, C, F, Sn> Stream for SnapshotOn<S, C, F>
+where
+    S: Stream,
+    C: Stream,
+    F: FnMut() -> Sn,
+{
+    type Item = WithSnapshot<S::Item, Sn>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        if !*this.control_done {
+            match this.control.poll_next(cx) {
+                Poll::Pending => (),
+                Poll::Ready(None) => *this.control_done = true,
+                Poll::Ready(Some(_)) => {
+                    return Poll::Ready(Some(Mixed::Snapshot((this.snapshot)())))
+                }
+            }
+        }
+
+        Poll::Ready(ready!(this.inner.poll_next(cx)).map(Mixed::Data))
+    }
+}
+
+impl<S> SnapshotOnStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use futures::{channel::mpsc, poll, stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn without_requests_items_pass_through() {
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .snapshot_on(stream::empty::<()>(), || "snapshot")
+                .collect::<Vec<_>>()
+                .await,
+            vec![Mixed::Data(1), Mixed::Data(2), Mixed::Data(3)]
+        );
+    }
+
+    #[tokio::test]
+    async fn snapshots_are_interleaved_at_the_requested_points() {
+        let (data_tx, data_rx) = mpsc::unbounded();
+        let (control_tx, control_rx) = mpsc::unbounded();
+        let seen = Rc::new(Cell::new(0));
+
+        let mut snapshots = data_rx
+            .inspect({
+                let seen = seen.clone();
+                move |_| seen.set(seen.get() + 1)
+            })
+            .snapshot_on(control_rx, {
+                let seen = seen.clone();
+                move || seen.get()
+            });
+
+        assert_eq!(poll!(snapshots.next()), Poll::Pending);
+
+        data_tx.unbounded_send('a').unwrap();
+        data_tx.unbounded_send('b').unwrap();
+        control_tx.unbounded_send(()).unwrap();
+        assert_eq!(
+            poll!(snapshots.next()),
+            Poll::Ready(Some(Mixed::Snapshot(0)))
+        );
+        assert_eq!(poll!(snapshots.next()), Poll::Ready(Some(Mixed::Data('a'))));
+        assert_eq!(poll!(snapshots.next()), Poll::Ready(Some(Mixed::Data('b'))));
+
+        control_tx.unbounded_send(()).unwrap();
+        assert_eq!(
+            poll!(snapshots.next()),
+            Poll::Ready(Some(Mixed::Snapshot(2)))
+        );
+        assert_eq!(poll!(snapshots.next()), Poll::Pending);
+
+        drop(control_tx);
+        data_tx.unbounded_send('c').unwrap();
+        drop(data_tx);
+        assert_eq!(poll!(snapshots.next()), Poll::Ready(Some(Mixed::Data('c'))));
+        assert_eq!(poll!(snapshots.next()), Poll::Ready(None));
+    }
+}
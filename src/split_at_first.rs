@@ -0,0 +1,285 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use futures::Stream;
+
+use crate::sync_utils::{lock, wake, wake_on_drop};
+
+pub trait SplitAtFirstStreamExt: Stream + Sized {
+    /// Split the stream at the first item matching `f`.
+    ///
+    /// [`Before`] yields the items preceding the first match and then ends. [`After`] yields the items following it,
+    /// preceded by the matching item itself if `delimiter` is [`Delimiter::Include`]. The matching item is never
+    /// duplicated.
+    ///
+    /// Both halves share the upstream: if `After` is polled before `Before` has reached the split point,
+    /// the preceding items are buffered for `Before`, unless it has been dropped.
+    fn split_at_first<F>(self, f: F, delimiter: Delimiter) -> (Before<Self, F>, After<Self, F>)
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        let shared = Arc::new(Mutex::new(Shared {
+            inner: Box::pin(self),
+            f,
+            delimiter,
+            state: SplitState::Searching,
+            before: VecDeque::new(),
+            held_delimiter: None,
+            before_waker: None,
+            after_waker: None,
+            before_dropped: false,
+            after_dropped: false,
+        }));
+        (
+            Before {
+                shared: shared.clone(),
+            },
+            After { shared },
+        )
+    }
+}
+
+/// Whether the item the stream is split at goes to [`After`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delimiter {
+    Include,
+    Exclude,
+}
+
+/// The first half of [`split_at_first`](`SplitAtFirstStreamExt::split_at_first`).
+#[derive(Debug)]
+pub struct Before<S: Stream, F> {
+    shared: Arc<Mutex<Shared<S, F, S::Item>>>,
+}
+
+/// The second half of [`split_at_first`](`SplitAtFirstStreamExt::split_at_first`).
+#[derive(Debug)]
+pub struct After<S: Stream, F> {
+    shared: Arc<Mutex<Shared<S, F, S::Item>>>,
+}
+
+#[derive(Debug)]
+struct Shared<S, F, I> {
+    inner: Pin<Box<S>>,
+    f: F,
+    delimiter: Delimiter,
+
+    state: SplitState,
+    before: VecDeque<I>,
+    held_delimiter: Option<I>,
+    before_waker: Option<Waker>,
+    after_waker: Option<Waker>,
+    before_dropped: bool,
+    after_dropped: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SplitState {
+    Searching,
+    Split,
+    Ended,
+}
+
+impl<S, F> Stream for Before<S, F>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> bool,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut shared = lock(&self.shared);
+        let shared = &mut *shared;
+
+        if let Some(item) = shared.before.pop_front() {
+            return Poll::Ready(Some(item));
+        }
+        if shared.state != SplitState::Searching {
+            return Poll::Ready(None);
+        }
+
+        match shared.inner.as_mut().poll_next(cx) {
+            Poll::Pending => {
+                shared.before_waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+            Poll::Ready(None) => {
+                shared.state = SplitState::Ended;
+                wake(&mut shared.after_waker);
+                Poll::Ready(None)
+            }
+            Poll::Ready(Some(item)) if (shared.f)(&item) => {
+                shared.state = SplitState::Split;
+                if shared.delimiter == Delimiter::Include && !shared.after_dropped {
+                    shared.held_delimiter = Some(item);
+                }
+                wake(&mut shared.after_waker);
+                Poll::Ready(None)
+            }
+            Poll::Ready(Some(item)) => Poll::Ready(Some(item)),
+        }
+    }
+}
+
+impl<S, F> Stream for After<S, F>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> bool,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut shared = lock(&self.shared);
+        let shared = &mut *shared;
+
+        loop {
+            match shared.state {
+                SplitState::Ended => return Poll::Ready(None),
+                SplitState::Split => {
+                    if let Some(item) = shared.held_delimiter.take() {
+                        return Poll::Ready(Some(item));
+                    }
+                    let poll = shared.inner.as_mut().poll_next(cx);
+                    if let Poll::Ready(None) = poll {
+                        shared.state = SplitState::Ended;
+                    }
+                    return poll;
+                }
+                SplitState::Searching => match shared.inner.as_mut().poll_next(cx) {
+                    Poll::Pending => {
+                        shared.after_waker = Some(cx.waker().clone());
+                        return Poll::Pending;
+                    }
+                    Poll::Ready(None) => {
+                        shared.state = SplitState::Ended;
+                        wake(&mut shared.before_waker);
+                        return Poll::Ready(None);
+                    }
+                    Poll::Ready(Some(item)) if (shared.f)(&item) => {
+                        shared.state = SplitState::Split;
+                        wake(&mut shared.before_waker);
+                        if shared.delimiter == Delimiter::Include {
+                            return Poll::Ready(Some(item));
+                        }
+                    }
+                    Poll::Ready(Some(_)) if shared.before_dropped => (),
+                    Poll::Ready(Some(item)) => {
+                        shared.before.push_back(item);
+                        wake(&mut shared.before_waker);
+                    }
+                },
+            }
+        }
+    }
+}
+
+impl<S: Stream, F> Drop for Before<S, F> {
+    fn drop(&mut self) {
+        let mut shared = lock(&self.shared);
+        shared.before_dropped = true;
+        shared.before.clear();
+        wake_on_drop(shared.after_waker.take());
+    }
+}
+
+impl<S: Stream, F> Drop for After<S, F> {
+    fn drop(&mut self) {
+        let mut shared = lock(&self.shared);
+        shared.after_dropped = true;
+        shared.held_delimiter = None;
+        wake_on_drop(shared.before_waker.take());
+    }
+}
+
+impl<S> SplitAtFirstStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn split_excluding_the_delimiter() {
+        let (before, after) =
+            stream::iter([1, 2, 0, 3, 4]).split_at_first(|x| *x == 0, Delimiter::Exclude);
+
+        assert_eq!(before.collect::<Vec<_>>().await, vec![1, 2]);
+        assert_eq!(after.collect::<Vec<_>>().await, vec![3, 4]);
+    }
+
+    #[tokio::test]
+    async fn split_including_the_delimiter() {
+        let (before, after) =
+            stream::iter([1, 2, 0, 3, 0]).split_at_first(|x| *x == 0, Delimiter::Include);
+
+        assert_eq!(before.collect::<Vec<_>>().await, vec![1, 2]);
+        assert_eq!(after.collect::<Vec<_>>().await, vec![0, 3, 0]);
+    }
+
+    #[tokio::test]
+    async fn after_polled_first_buffers_the_items_for_before() {
+        let (before, after) =
+            stream::iter([1, 2, 0, 3, 4]).split_at_first(|x| *x == 0, Delimiter::Include);
+
+        assert_eq!(after.collect::<Vec<_>>().await, vec![0, 3, 4]);
+        assert_eq!(before.collect::<Vec<_>>().await, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn no_match() {
+        let (before, after) =
+            stream::iter([1, 2, 3]).split_at_first(|x| *x == 0, Delimiter::Include);
+
+        assert_eq!(after.collect::<Vec<_>>().await, vec![]);
+        assert_eq!(before.collect::<Vec<_>>().await, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn halves_driven_concurrently() {
+        let (before, after) = stream::iter(0..10)
+            .then(|x| async move {
+                tokio::task::yield_now().await;
+                x
+            })
+            .boxed()
+            .split_at_first(|x| *x == 5, Delimiter::Exclude);
+
+        let (before, after) = tokio::join!(
+            tokio::spawn(before.collect::<Vec<_>>()),
+            tokio::spawn(after.collect::<Vec<_>>()),
+        );
+        assert_eq!(before.unwrap(), vec![0, 1, 2, 3, 4]);
+        assert_eq!(after.unwrap(), vec![6, 7, 8, 9]);
+    }
+
+    #[tokio::test]
+    async fn dropping_the_last_poller_wakes_the_other_half() {
+        use std::sync::atomic::Ordering;
+
+        use futures::{channel::mpsc, poll};
+
+        use crate::test_utils::flag_waker;
+
+        let (tx, rx) = mpsc::unbounded();
+        let (mut before, after) = rx.split_at_first(|x| *x == 0, Delimiter::Exclude);
+        let mut after = Box::pin(after);
+
+        let (waker, woken) = flag_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert_eq!(after.as_mut().poll_next(&mut cx), Poll::Pending);
+        assert_eq!(poll!(before.next()), Poll::Pending);
+
+        drop(before);
+        tx.unbounded_send(1).unwrap();
+        assert!(woken.load(Ordering::Relaxed));
+
+        tx.unbounded_send(0).unwrap();
+        tx.unbounded_send(2).unwrap();
+        assert_eq!(after.as_mut().poll_next(&mut cx), Poll::Ready(Some(2)));
+    }
+}
@@ -0,0 +1,235 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use futures::Stream;
+
+use crate::sync_utils::{lock, wake, wake_on_drop};
+
+pub trait SplitByThresholdStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: PartialOrd + Clone,
+{
+    /// Route the items `>= level` to [`AboveStream`] and the rest to [`BelowStream`].
+    ///
+    /// Both halves share the upstream: whichever half is polled pulls the items and buffers those meant for
+    /// the other half. There is no backpressure between the halves, so the buffer of a half that is not
+    /// polled grows without bound; once a half is dropped, its items are discarded instead.
+    fn split_by_threshold(self, level: Self::Item) -> (AboveStream<Self>, BelowStream<Self>) {
+        let shared = Arc::new(Mutex::new(Shared {
+            inner: Box::pin(self),
+            level,
+            ended: false,
+            above: Half::new(),
+            below: Half::new(),
+        }));
+        (
+            AboveStream {
+                shared: shared.clone(),
+            },
+            BelowStream { shared },
+        )
+    }
+}
+
+/// The half of [`split_by_threshold`](`SplitByThresholdStreamExt::split_by_threshold`) with the items `>= level`.
+#[derive(Debug)]
+pub struct AboveStream<S: Stream> {
+    shared: Arc<Mutex<Shared<S, S::Item>>>,
+}
+
+/// The half of [`split_by_threshold`](`SplitByThresholdStreamExt::split_by_threshold`) with the items `< level`.
+#[derive(Debug)]
+pub struct BelowStream<S: Stream> {
+    shared: Arc<Mutex<Shared<S, S::Item>>>,
+}
+
+#[derive(Debug)]
+struct Shared<S, I> {
+    inner: Pin<Box<S>>,
+    level: I,
+
+    ended: bool,
+    above: Half<I>,
+    below: Half<I>,
+}
+
+#[derive(Debug)]
+struct Half<I> {
+    buffer: VecDeque<I>,
+    waker: Option<Waker>,
+    dropped: bool,
+}
+
+impl<I> Half<I> {
+    fn new() -> Self {
+        Self {
+            buffer: VecDeque::new(),
+            waker: None,
+            dropped: false,
+        }
+    }
+}
+
+impl<S> Shared<S, S::Item>
+where
+    S: Stream,
+    S::Item: PartialOrd,
+{
+    fn poll_half(&mut self, above: bool, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        loop {
+            let (mine, theirs) = if above {
+                (&mut self.above, &mut self.below)
+            } else {
+                (&mut self.below, &mut self.above)
+            };
+
+            if let Some(item) = mine.buffer.pop_front() {
+                return Poll::Ready(Some(item));
+            }
+            if self.ended {
+                return Poll::Ready(None);
+            }
+
+            match self.inner.as_mut().poll_next(cx) {
+                Poll::Pending => {
+                    mine.waker = Some(cx.waker().clone());
+                    return Poll::Pending;
+                }
+                Poll::Ready(None) => {
+                    self.ended = true;
+                    wake(&mut theirs.waker);
+                    return Poll::Ready(None);
+                }
+                Poll::Ready(Some(item)) if (item >= self.level) == above => {
+                    return Poll::Ready(Some(item))
+                }
+                Poll::Ready(Some(_)) if theirs.dropped => (),
+                Poll::Ready(Some(item)) => {
+                    theirs.buffer.push_back(item);
+                    wake(&mut theirs.waker);
+                }
+            }
+        }
+    }
+}
+
+impl<S> Stream for AboveStream<S>
+where
+    S: Stream,
+    S::Item: PartialOrd,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        lock(&self.shared).poll_half(true, cx)
+    }
+}
+
+impl<S> Stream for BelowStream<S>
+where
+    S: Stream,
+    S::Item: PartialOrd,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        lock(&self.shared).poll_half(false, cx)
+    }
+}
+
+impl<S: Stream> Drop for AboveStream<S> {
+    fn drop(&mut self) {
+        let mut shared = lock(&self.shared);
+        shared.above.dropped = true;
+        shared.above.buffer.clear();
+        wake_on_drop(shared.below.waker.take());
+    }
+}
+
+impl<S: Stream> Drop for BelowStream<S> {
+    fn drop(&mut self) {
+        let mut shared = lock(&self.shared);
+        shared.below.dropped = true;
+        shared.below.buffer.clear();
+        wake_on_drop(shared.above.waker.take());
+    }
+}
+
+impl<S> SplitByThresholdStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: PartialOrd + Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{channel::mpsc, poll, stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn items_are_routed_by_the_level() {
+        let (above, below) = stream::iter([3, 7, 5, 1, 9, 4]).split_by_threshold(5);
+
+        assert_eq!(above.collect::<Vec<_>>().await, vec![7, 5, 9]);
+        assert_eq!(below.collect::<Vec<_>>().await, vec![3, 1, 4]);
+    }
+
+    #[tokio::test]
+    async fn halves_polled_in_turns() {
+        let (tx, rx) = mpsc::unbounded();
+        let (mut above, mut below) = rx.split_by_threshold(0.0);
+
+        assert_eq!(poll!(above.next()), Poll::Pending);
+
+        tx.unbounded_send(-1.0).unwrap();
+        tx.unbounded_send(2.0).unwrap();
+        assert_eq!(poll!(above.next()), Poll::Ready(Some(2.0)));
+        assert_eq!(poll!(above.next()), Poll::Pending);
+        assert_eq!(poll!(below.next()), Poll::Ready(Some(-1.0)));
+
+        tx.unbounded_send(0.0).unwrap();
+        tx.unbounded_send(-3.0).unwrap();
+        assert_eq!(poll!(below.next()), Poll::Ready(Some(-3.0)));
+
+        drop(tx);
+        assert_eq!(poll!(below.next()), Poll::Ready(None));
+        assert_eq!(poll!(above.next()), Poll::Ready(Some(0.0)));
+        assert_eq!(poll!(above.next()), Poll::Ready(None));
+    }
+
+    #[tokio::test]
+    async fn items_of_a_dropped_half_are_discarded() {
+        let (above, below) = stream::iter([1, 10, 2, 20]).split_by_threshold(5);
+        drop(above);
+
+        assert_eq!(below.collect::<Vec<_>>().await, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn dropping_the_last_poller_wakes_the_other_half() {
+        use std::sync::atomic::Ordering;
+
+        use crate::test_utils::flag_waker;
+
+        let (tx, rx) = mpsc::unbounded();
+        let (mut above, below) = rx.split_by_threshold(5);
+        let mut below = Box::pin(below);
+
+        let (waker, woken) = flag_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert_eq!(below.as_mut().poll_next(&mut cx), Poll::Pending);
+        assert_eq!(poll!(above.next()), Poll::Pending);
+
+        drop(above);
+        tx.unbounded_send(1).unwrap();
+        assert!(woken.load(Ordering::Relaxed));
+        assert_eq!(below.as_mut().poll_next(&mut cx), Poll::Ready(Some(1)));
+    }
+}
@@ -0,0 +1,157 @@
+use std::{
+    ops::Sub,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+use crate::drain;
+
+pub trait StableBurstStreamExt
+where
+    Self: Stream + Sized,
+    Self::Item: PartialOrd + Sub<Output = Self::Item> + Clone,
+{
+    /// Similar to [`latest_ready`](`crate::latest_ready::LatestReadyStreamExt::latest_ready`), but the last item
+    /// of a burst is yielded only if all the items of that burst stay within `epsilon` of each other.
+    ///
+    /// Volatile bursts yield nothing. Same as with `latest_ready`, a burst ended by the end of the upstream
+    /// is not yielded. A burst is drained a bounded number of items per poll and tracked across the polls.
+    fn stable_burst(self, epsilon: Self::Item) -> StableBurst<Self, Self::Item> {
+        StableBurst::new(self, epsilon)
+    }
+}
+
+/// Stream for [`stable_burst`](`StableBurstStreamExt::stable_burst`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct StableBurst<Stream, Item> {
+    #[pin]
+    inner: Stream,
+    epsilon: Item,
+
+    burst: Option<(Item, Item, Item)>,
+}
+
+impl<S> StableBurst<S, S::Item>
+where
+    S: Stream,
+    S::Item: PartialOrd + Sub<Output = S::Item> + Clone,
+{
+    pub fn new(inner: S, epsilon: S::Item) -> Self {
+        Self {
+            inner,
+            epsilon,
+            burst: None,
+        }
+    }
+}
+
+impl<S> Stream for StableBurst<S, S::Item>
+where
+    S: Stream,
+    S::Item: PartialOrd + Sub<Output = S::Item> + Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        let mut budget = drain::BUDGET;
+        loop {
+            if budget == 0 {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            budget -= 1;
+            match this.inner.as_mut().poll_next(cx) {
+                Poll::Pending => break,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Ready(Some(item)) => {
+                    *this.burst = Some(match this.burst.take() {
+                        None => (item.clone(), item.clone(), item),
+                        Some((min, max, _)) => (
+                            if item < min { item.clone() } else { min },
+                            if item > max { item.clone() } else { max },
+                            item,
+                        ),
+                    });
+                }
+            }
+        }
+
+        // Either way the upstream has returned `Pending`, so `cx` is registered for the next burst.
+        match this.burst.take() {
+            Some((min, max, last)) if max.clone() - min.clone() <= *this.epsilon => {
+                Poll::Ready(Some(last))
+            }
+            _ => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<S> StableBurstStreamExt for S
+where
+    S: Stream + Sized,
+    S::Item: PartialOrd + Sub<Output = S::Item> + Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+    use crate::test_utils::ready_after_n_polls;
+
+    #[tokio::test]
+    async fn only_stable_bursts_are_yielded() {
+        assert_eq!(
+            stream::iter([
+                vec![5.0, 5.1, 4.9],
+                vec![1.0, 9.0, 5.0],
+                vec![7.0],
+                vec![3.0, 3.4, 3.2],
+                vec![0.0],
+            ])
+            .map(stream::iter)
+            .then(|chunk| ready_after_n_polls(chunk, 1))
+            .flatten()
+            .stable_burst(0.5)
+            .collect::<Vec<_>>()
+            .await,
+            vec![4.9, 7.0, 3.2]
+        );
+    }
+
+    #[tokio::test]
+    async fn the_final_burst_is_not_yielded() {
+        assert!(stream::iter([1, 1, 1])
+            .stable_burst(0)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn a_long_burst_is_tracked_across_polls() {
+        let volatile = std::iter::once(5).chain([1; 99]).collect::<Vec<_>>();
+        assert_eq!(
+            stream::iter([volatile, vec![1, 1]])
+                .map(stream::iter)
+                .then(|chunk| ready_after_n_polls(chunk, 1))
+                .flatten()
+                .chain(stream::pending())
+                .stable_burst(0)
+                .take(1)
+                .collect::<Vec<_>>()
+                .await,
+            vec![1]
+        );
+    }
+}
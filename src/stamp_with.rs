@@ -0,0 +1,160 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{stream::FusedStream, Stream};
+
+use crate::drain;
+
+pub trait StampWithStreamExt: Stream + Sized {
+    /// Pair each item with the latest tick of the logical `clock`, or with `0` if it has not ticked yet.
+    ///
+    /// `clock` is drained eagerly, a bounded number of ticks per poll, before `self` is polled;
+    /// once it ends, its last tick holds.
+    /// The stream ends when `self` ends.
+    fn stamp_with<C>(self, clock: C) -> StampWith<Self, C>
+    where
+        C: Stream<Item = u64>,
+    {
+        StampWith::new(self, clock)
+    }
+}
+
+/// Stream for [`stamp_with`](`StampWithStreamExt::stamp_with`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct StampWith<Stream, C> {
+    #[pin]
+    inner: Stream,
+    #[pin]
+    clock: C,
+
+    tick: u64,
+    clock_done: bool,
+    terminated: bool,
+}
+
+impl<S, C> StampWith<S, C> {
+    pub fn new(inner: S, clock: C) -> Self {
+        Self {
+            inner,
+            clock,
+            tick: 0,
+            clock_done: false,
+            terminated: false,
+        }
+    }
+}
+
+impl<S, C> Stream for StampWith<S, C>
+where
+    S: Stream,
+    C: Stream<Item = u64>,
+{
+    type Item = (u64, S::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        if *this.terminated {
+            return Poll::Ready(None);
+        }
+
+        let mut budget = drain::BUDGET;
+        while !*this.clock_done {
+            if budget == 0 {
+                cx.waker().wake_by_ref();
+                break;
+            }
+            budget -= 1;
+            match this.clock.as_mut().poll_next(cx) {
+                Poll::Pending => break,
+                Poll::Ready(None) => *this.clock_done = true,
+                Poll::Ready(Some(tick)) => *this.tick = tick,
+            }
+        }
+
+        let Some(item) = ready!(this.inner.poll_next(cx)) else {
+            *this.terminated = true;
+            return Poll::Ready(None);
+        };
+        Poll::Ready(Some((*this.tick, item)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.terminated {
+            return (0, Some(0));
+        }
+        self.inner.size_hint()
+    }
+}
+
+impl<S, C> FusedStream for StampWith<S, C>
+where
+    S: Stream,
+    C: Stream<Item = u64>,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<S> StampWithStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{channel::mpsc, poll, stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn items_are_stamped_with_the_latest_tick() {
+        let (data_tx, data_rx) = mpsc::unbounded();
+        let (clock_tx, clock_rx) = mpsc::unbounded();
+        let mut stamped = data_rx.stamp_with(clock_rx);
+
+        data_tx.unbounded_send('a').unwrap();
+        assert_eq!(poll!(stamped.next()), Poll::Ready(Some((0, 'a'))));
+
+        clock_tx.unbounded_send(1).unwrap();
+        data_tx.unbounded_send('b').unwrap();
+        data_tx.unbounded_send('c').unwrap();
+        assert_eq!(poll!(stamped.next()), Poll::Ready(Some((1, 'b'))));
+        assert_eq!(poll!(stamped.next()), Poll::Ready(Some((1, 'c'))));
+
+        clock_tx.unbounded_send(2).unwrap();
+        clock_tx.unbounded_send(3).unwrap();
+        assert_eq!(poll!(stamped.next()), Poll::Pending);
+        data_tx.unbounded_send('d').unwrap();
+        assert_eq!(poll!(stamped.next()), Poll::Ready(Some((3, 'd'))));
+
+        drop(data_tx);
+        assert_eq!(poll!(stamped.next()), Poll::Ready(None));
+        assert!(stamped.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn the_last_tick_holds_after_the_clock_ends() {
+        assert_eq!(
+            stream::iter(['a', 'b'])
+                .stamp_with(stream::iter([4, 7]))
+                .collect::<Vec<_>>()
+                .await,
+            vec![(7, 'a'), (7, 'b')]
+        );
+    }
+
+    #[tokio::test]
+    async fn an_always_ready_clock_does_not_starve_the_items() {
+        let stamped = stream::iter(['a', 'b', 'c'])
+            .stamp_with(stream::iter(1..))
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(stamped.len(), 3);
+        assert!(stamped.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+}
@@ -0,0 +1,177 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait StatefulByKeyLruStreamExt: Stream + Sized {
+    /// Map each item with `update` against the state of its key, as computed with `key`.
+    ///
+    /// The state of a key starts as a clone of `init`. At most `capacity` states are kept: making room for a new key
+    /// evicts the least recently used one, whose state starts over from `init` if the key comes back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    fn stateful_by_key_lru<K, St, F, F2, T>(
+        self,
+        capacity: usize,
+        key: F,
+        init: St,
+        update: F2,
+    ) -> StatefulByKeyLru<Self, K, St, F, F2>
+    where
+        K: Eq + Hash + Clone,
+        St: Clone,
+        F: FnMut(&Self::Item) -> K,
+        F2: FnMut(&mut St, Self::Item) -> T,
+    {
+        StatefulByKeyLru::new(self, capacity, key, init, update)
+    }
+}
+
+/// Stream for [`stateful_by_key_lru`](`StatefulByKeyLruStreamExt::stateful_by_key_lru`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct StatefulByKeyLru<Stream, K, St, F, F2> {
+    #[pin]
+    inner: Stream,
+    capacity: usize,
+    key: F,
+    init: St,
+    update: F2,
+
+    states: HashMap<K, (u64, St)>,
+    recency: BTreeMap<u64, K>,
+    clock: u64,
+}
+
+impl<S, K, St, F, F2> StatefulByKeyLru<S, K, St, F, F2> {
+    pub fn new(inner: S, capacity: usize, key: F, init: St, update: F2) -> Self {
+        assert!(capacity > 0, "the capacity must not be zero");
+        Self {
+            inner,
+            capacity,
+            key,
+            init,
+            update,
+            states: HashMap::with_capacity(capacity),
+            recency: BTreeMap::new(),
+            clock: 0,
+        }
+    }
+}
+
+impl<S, K, St, F, F2, T> Stream for StatefulByKeyLru<S, K, St, F, F2>
+where
+    S: Stream,
+    K: Eq + Hash + Clone,
+    St: Clone,
+    F: FnMut(&S::Item) -> K,
+    F2: FnMut(&mut St, S::Item) -> T,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        let Some(item) = ready!(this.inner.poll_next(cx)) else {
+            return Poll::Ready(None);
+        };
+
+        let key = (this.key)(&item);
+        *this.clock += 1;
+        let used_at = *this.clock;
+
+        if let Some((last_used, _)) = this.states.get_mut(&key) {
+            this.recency.remove(last_used);
+            *last_used = used_at;
+        } else {
+            if this.states.len() == *this.capacity {
+                let (_, evicted) = this.recency.pop_first().expect("the states are full");
+                this.states.remove(&evicted);
+            }
+            this.states
+                .insert(key.clone(), (used_at, this.init.clone()));
+        }
+        this.recency.insert(used_at, key.clone());
+
+        let (_, state) = this.states.get_mut(&key).expect("the key has a state");
+        Poll::Ready(Some((this.update)(state, item)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S> StatefulByKeyLruStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    fn running_count(count: &mut usize, (key, _): (char, i32)) -> (char, usize) {
+        *count += 1;
+        (key, *count)
+    }
+
+    #[tokio::test]
+    async fn states_are_kept_per_key() {
+        assert_eq!(
+            stream::iter([('a', 1), ('b', 2), ('a', 3), ('a', 4), ('b', 5)])
+                .stateful_by_key_lru(
+                    2,
+                    |(key, _)| *key,
+                    0,
+                    |sum: &mut i32, (key, n)| {
+                        *sum += n;
+                        (key, *sum)
+                    }
+                )
+                .collect::<Vec<_>>()
+                .await,
+            vec![('a', 1), ('b', 2), ('a', 4), ('a', 8), ('b', 7)]
+        );
+    }
+
+    #[tokio::test]
+    async fn evicted_keys_start_over() {
+        assert_eq!(
+            stream::iter([('a', 0), ('b', 0), ('a', 0), ('c', 0), ('a', 0), ('b', 0)])
+                .stateful_by_key_lru(2, |(key, _)| *key, 0, running_count)
+                .collect::<Vec<_>>()
+                .await,
+            vec![('a', 1), ('b', 1), ('a', 2), ('c', 1), ('a', 3), ('b', 1)]
+        );
+    }
+
+    #[tokio::test]
+    async fn the_least_recently_used_key_is_evicted() {
+        assert_eq!(
+            stream::iter([('a', 0), ('b', 0), ('c', 0), ('b', 0), ('c', 0), ('a', 0)])
+                .stateful_by_key_lru(2, |(key, _)| *key, 0, running_count)
+                .collect::<Vec<_>>()
+                .await,
+            vec![('a', 1), ('b', 1), ('c', 1), ('b', 2), ('c', 2), ('a', 1)]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_capacity_is_rejected() {
+        let _ = stream::empty::<(char, i32)>().stateful_by_key_lru(
+            0,
+            |(key, _)| *key,
+            0,
+            running_count,
+        );
+    }
+}
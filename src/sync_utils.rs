@@ -0,0 +1,24 @@
+use std::{
+    sync::{Mutex, MutexGuard},
+    task::Waker,
+};
+
+pub(crate) fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+pub(crate) fn wake(waker: &mut Option<Waker>) {
+    if let Some(waker) = waker.take() {
+        waker.wake();
+    }
+}
+
+/// Wake the other streams sharing an upstream with the one being dropped.
+///
+/// The dropped stream may have been the last to poll the upstream, so the upstream may hold
+/// its waker only: without a wake-up, the others would miss the items arriving from now on.
+pub(crate) fn wake_on_drop(others: impl IntoIterator<Item = Waker>) {
+    others.into_iter().for_each(Waker::wake);
+}
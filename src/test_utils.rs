@@ -1,8 +1,14 @@
 use std::future;
 use std::future::Future;
-use std::task::Poll;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Poll, Waker};
 
-use futures::FutureExt;
+use futures::{
+    stream,
+    task::{self, ArcWake},
+    FutureExt, Stream, StreamExt,
+};
 
 pub fn ready_after_n_polls<V>(value: V, mut polls: usize) -> impl Future<Output = V> {
     let mut value = Some(value);
@@ -17,3 +23,63 @@ pub fn ready_after_n_polls<V>(value: V, mut polls: usize) -> impl Future<Output
     })
     .fuse()
 }
+
+/// Wrap `stream` so that every call to its `poll_next` is counted.
+pub fn counting_polls<S>(mut stream: S) -> (impl Stream<Item = S::Item>, Arc<AtomicUsize>)
+where
+    S: Stream + Unpin,
+{
+    let polls = Arc::new(AtomicUsize::new(0));
+    let counter = polls.clone();
+    let counted = stream::poll_fn(move |cx| {
+        counter.fetch_add(1, Ordering::Relaxed);
+        stream.poll_next_unpin(cx)
+    });
+    (counted, polls)
+}
+
+/// A clock that only moves when told to, handing out delays measured in its ticks.
+#[derive(Debug, Clone, Default)]
+pub struct ManualClock {
+    state: Arc<Mutex<(u64, Vec<Waker>)>>,
+}
+
+impl ManualClock {
+    /// A future that completes once the clock has advanced by `ticks` from now.
+    pub fn delay(&self, ticks: u64) -> impl Future<Output = ()> {
+        let clock = self.clone();
+        let deadline = self.state.lock().unwrap().0 + ticks;
+        future::poll_fn(move |cx| {
+            let mut state = clock.state.lock().unwrap();
+            if state.0 >= deadline {
+                Poll::Ready(())
+            } else {
+                state.1.push(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+    }
+
+    pub fn advance(&self, ticks: u64) {
+        let wakers = {
+            let mut state = self.state.lock().unwrap();
+            state.0 += ticks;
+            std::mem::take(&mut state.1)
+        };
+        wakers.into_iter().for_each(Waker::wake);
+    }
+}
+
+struct FlagWaker(Arc<AtomicBool>);
+
+impl ArcWake for FlagWaker {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+/// A waker that raises the returned flag when woken.
+pub fn flag_waker() -> (Waker, Arc<AtomicBool>) {
+    let woken = Arc::new(AtomicBool::new(false));
+    (task::waker(Arc::new(FlagWaker(woken.clone()))), woken)
+}
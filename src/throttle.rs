@@ -0,0 +1,213 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{stream::FusedStream, Stream};
+
+use crate::drain;
+
+pub trait ThrottleStreamExt: Stream + Sized {
+    /// Emit at most one item per window, a window being a delay made by `delay`.
+    ///
+    /// A window starts with the first item arriving while idle; which item of the window is emitted
+    /// is decided by `edge`. The upstream is drained a bounded number of items per poll. Being generic
+    /// over the delay, the adapter is not tied to a runtime: with tokio, pass `|| tokio::time::sleep(period)`.
+    fn throttle<F, D>(self, edge: ThrottleEdge, delay: F) -> Throttle<Self, F, D, Self::Item>
+    where
+        F: FnMut() -> D,
+        D: Future,
+    {
+        Throttle::new(self, edge, delay)
+    }
+}
+
+/// Which item of a window [`Throttle`] emits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThrottleEdge {
+    /// Emit the item that opens the window right away, dropping the rest of the window.
+    Leading,
+    /// Emit the latest item of the window once it closes.
+    /// If the upstream ends mid-window, the latest item is emitted right away.
+    Trailing,
+}
+
+/// Stream for [`throttle`](`ThrottleStreamExt::throttle`) method.
+#[derive(Debug)]
+#[pin_project::pin_project]
+pub struct Throttle<Stream, F, D, Item> {
+    #[pin]
+    inner: Stream,
+    edge: ThrottleEdge,
+    make_delay: F,
+
+    #[pin]
+    window: Option<D>,
+    latest: Option<Item>,
+    inner_done: bool,
+}
+
+impl<S, F, D, I> Throttle<S, F, D, I> {
+    pub fn new(inner: S, edge: ThrottleEdge, make_delay: F) -> Self {
+        Self {
+            inner,
+            edge,
+            make_delay,
+            window: None,
+            latest: None,
+            inner_done: false,
+        }
+    }
+}
+
+impl<S, F, D> Stream for Throttle<S, F, D, S::Item>
+where
+    S: Stream,
+    F: FnMut() -> D,
+    D: Future,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        let mut budget = drain::BUDGET;
+        while !*this.inner_done {
+            if budget == 0 {
+                cx.waker().wake_by_ref();
+                break;
+            }
+            budget -= 1;
+            match this.inner.as_mut().poll_next(cx) {
+                Poll::Pending => break,
+                Poll::Ready(None) => *this.inner_done = true,
+                Poll::Ready(Some(item)) => {
+                    let idle = this.window.is_none();
+                    if idle {
+                        this.window.set(Some((this.make_delay)()));
+                    }
+                    match this.edge {
+                        ThrottleEdge::Leading if idle => return Poll::Ready(Some(item)),
+                        ThrottleEdge::Leading => (),
+                        ThrottleEdge::Trailing => *this.latest = Some(item),
+                    }
+                }
+            }
+        }
+
+        if *this.inner_done {
+            this.window.set(None);
+            return Poll::Ready(this.latest.take());
+        }
+
+        let Some(window) = this.window.as_mut().as_pin_mut() else {
+            return Poll::Pending;
+        };
+        if window.poll(cx).is_pending() {
+            return Poll::Pending;
+        }
+        this.window.set(None);
+        // The upstream has returned `Pending` above or the task has woken itself, so the next window is not missed.
+        match this.latest.take() {
+            Some(item) => Poll::Ready(Some(item)),
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl<S, F, D> FusedStream for Throttle<S, F, D, S::Item>
+where
+    S: Stream,
+    F: FnMut() -> D,
+    D: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner_done && self.latest.is_none()
+    }
+}
+
+impl<S> ThrottleStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{channel::mpsc, poll, stream, StreamExt};
+
+    use super::*;
+    use crate::test_utils::ManualClock;
+
+    #[tokio::test]
+    async fn leading_edge_emits_the_first_item_of_each_window() {
+        let clock = ManualClock::default();
+        let (tx, rx) = mpsc::unbounded();
+        let mut throttled = rx.throttle(ThrottleEdge::Leading, || clock.delay(10));
+
+        tx.unbounded_send(1).unwrap();
+        tx.unbounded_send(2).unwrap();
+        assert_eq!(poll!(throttled.next()), Poll::Ready(Some(1)));
+        assert_eq!(poll!(throttled.next()), Poll::Pending);
+
+        clock.advance(5);
+        tx.unbounded_send(3).unwrap();
+        assert_eq!(poll!(throttled.next()), Poll::Pending);
+
+        clock.advance(5);
+        assert_eq!(poll!(throttled.next()), Poll::Pending);
+        tx.unbounded_send(4).unwrap();
+        tx.unbounded_send(5).unwrap();
+        assert_eq!(poll!(throttled.next()), Poll::Ready(Some(4)));
+        assert_eq!(poll!(throttled.next()), Poll::Pending);
+
+        drop(tx);
+        assert_eq!(poll!(throttled.next()), Poll::Ready(None));
+        assert!(throttled.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn trailing_edge_emits_the_last_item_of_each_window() {
+        let clock = ManualClock::default();
+        let (tx, rx) = mpsc::unbounded();
+        let mut throttled = rx.throttle(ThrottleEdge::Trailing, || clock.delay(10));
+
+        tx.unbounded_send(1).unwrap();
+        tx.unbounded_send(2).unwrap();
+        assert_eq!(poll!(throttled.next()), Poll::Pending);
+        clock.advance(5);
+        tx.unbounded_send(3).unwrap();
+        assert_eq!(poll!(throttled.next()), Poll::Pending);
+
+        clock.advance(5);
+        assert_eq!(poll!(throttled.next()), Poll::Ready(Some(3)));
+        assert_eq!(poll!(throttled.next()), Poll::Pending);
+
+        tx.unbounded_send(4).unwrap();
+        assert_eq!(poll!(throttled.next()), Poll::Pending);
+        clock.advance(10);
+        assert_eq!(poll!(throttled.next()), Poll::Ready(Some(4)));
+    }
+
+    #[tokio::test]
+    async fn trailing_edge_flushes_the_window_at_the_end() {
+        let clock = ManualClock::default();
+        let (tx, rx) = mpsc::unbounded();
+        let mut throttled = rx.throttle(ThrottleEdge::Trailing, || clock.delay(10));
+
+        tx.unbounded_send(1).unwrap();
+        tx.unbounded_send(2).unwrap();
+        assert_eq!(poll!(throttled.next()), Poll::Pending);
+        drop(tx);
+        assert_eq!(poll!(throttled.next()), Poll::Ready(Some(2)));
+        assert_eq!(poll!(throttled.next()), Poll::Ready(None));
+        assert!(throttled.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn an_always_ready_upstream_is_drained_in_bounded_steps() {
+        let clock = ManualClock::default();
+        let mut throttled = stream::repeat(1).throttle(ThrottleEdge::Trailing, || clock.delay(10));
+
+        assert_eq!(poll!(throttled.next()), Poll::Pending);
+        clock.advance(10);
+        assert_eq!(poll!(throttled.next()), Poll::Ready(Some(1)));
+    }
+}
@@ -0,0 +1,161 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::Stream;
+use tokio::time::Sleep;
+
+use crate::drain;
+
+pub trait ThrottleCountedStreamExt: Stream + Sized {
+    /// Emit at most one item per `period`, tagged with the number of upstream items coalesced into it.
+    ///
+    /// A period starts with the first item arriving while idle; when it elapses, the latest item of the period
+    /// is emitted together with the number of items that arrived during it (the emitted one included).
+    /// If the upstream ends mid-period, the pending item is emitted right away. The upstream is drained
+    /// a bounded number of items per poll, so that an always-ready one cannot keep the period from elapsing.
+    fn throttle_counted(self, period: Duration) -> ThrottleCounted<Self, Self::Item> {
+        ThrottleCounted::new(self, period)
+    }
+}
+
+/// Stream for [`throttle_counted`](`ThrottleCountedStreamExt::throttle_counted`) method.
+#[derive(Debug)]
+#[pin_project::pin_project]
+pub struct ThrottleCounted<Stream, Item> {
+    #[pin]
+    inner: Stream,
+    period: Duration,
+
+    deadline: Option<Pin<Box<Sleep>>>,
+    latest: Option<Item>,
+    count: usize,
+    terminated: bool,
+}
+
+impl<S> ThrottleCounted<S, S::Item>
+where
+    S: Stream,
+{
+    pub fn new(inner: S, period: Duration) -> Self {
+        Self {
+            inner,
+            period,
+            deadline: None,
+            latest: None,
+            count: 0,
+            terminated: false,
+        }
+    }
+}
+
+impl<S> Stream for ThrottleCounted<S, S::Item>
+where
+    S: Stream,
+{
+    type Item = (S::Item, usize);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        let mut budget = drain::BUDGET;
+        while !*this.terminated {
+            if budget == 0 {
+                cx.waker().wake_by_ref();
+                break;
+            }
+            budget -= 1;
+            match this.inner.as_mut().poll_next(cx) {
+                Poll::Pending => break,
+                Poll::Ready(None) => *this.terminated = true,
+                Poll::Ready(Some(item)) => {
+                    if this.deadline.is_none() {
+                        *this.deadline = Some(Box::pin(tokio::time::sleep(*this.period)));
+                    }
+                    *this.latest = Some(item);
+                    *this.count += 1;
+                }
+            }
+        }
+
+        let elapsed = *this.terminated
+            || this
+                .deadline
+                .as_mut()
+                .is_some_and(|deadline| deadline.as_mut().poll(cx).is_ready());
+        if !elapsed {
+            return Poll::Pending;
+        }
+
+        *this.deadline = None;
+        let count = std::mem::take(this.count);
+        Poll::Ready(this.latest.take().map(|item| (item, count)))
+    }
+}
+
+impl<S> ThrottleCountedStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    fn arrivals(schedule: impl IntoIterator<Item = (u64, i32)>) -> impl Stream<Item = i32> {
+        stream::iter(schedule).then(|(delay_ms, x)| async move {
+            tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+            x
+        })
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn counts_the_items_coalesced_per_period() {
+        let before = tokio::time::Instant::now();
+        let mut throttled = Box::pin(
+            arrivals([(0, 1), (10, 2), (10, 3), (100, 4), (5, 5), (100, 6)])
+                .throttle_counted(Duration::from_millis(50)),
+        );
+
+        assert_eq!(throttled.next().await, Some((3, 3)));
+        assert_eq!(before.elapsed(), Duration::from_millis(50));
+        assert_eq!(throttled.next().await, Some((5, 2)));
+        assert_eq!(before.elapsed(), Duration::from_millis(170));
+        assert_eq!(throttled.next().await, Some((6, 1)));
+        assert_eq!(throttled.next().await, None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sparse_items_are_not_coalesced() {
+        assert_eq!(
+            arrivals([(0, 1), (30, 2), (30, 3)])
+                .throttle_counted(Duration::from_millis(10))
+                .collect::<Vec<_>>()
+                .await,
+            vec![(1, 1), (2, 1), (3, 1)]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn pending_item_is_flushed_when_the_upstream_ends() {
+        let before = tokio::time::Instant::now();
+        assert_eq!(
+            arrivals([(0, 1), (5, 2)])
+                .throttle_counted(Duration::from_secs(1))
+                .collect::<Vec<_>>()
+                .await,
+            vec![(2, 2)]
+        );
+        assert_eq!(before.elapsed(), Duration::from_millis(5));
+    }
+
+    #[tokio::test]
+    async fn an_always_ready_upstream_is_still_throttled() {
+        let mut throttled = Box::pin(stream::repeat(1).throttle_counted(Duration::from_millis(1)));
+        let (item, count) = throttled.next().await.unwrap();
+        assert_eq!(item, 1);
+        assert!(count >= drain::BUDGET);
+    }
+}
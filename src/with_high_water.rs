@@ -0,0 +1,165 @@
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+/// A stream that holds some of the upstream items internally before yielding them.
+pub trait Buffered {
+    /// Number of items currently held.
+    fn buffered_len(&self) -> usize;
+}
+
+pub trait WithHighWaterStreamExt: Stream + Buffered + Sized {
+    /// Pass the items through, recording the peak of [`buffered_len`](`Buffered::buffered_len`)
+    /// observed after each poll. The peak is available via [`HighWaterHandle::peak`].
+    fn with_high_water(self) -> (WithHighWater<Self>, HighWaterHandle) {
+        WithHighWater::new(self)
+    }
+}
+
+/// Stream for [`with_high_water`](`WithHighWaterStreamExt::with_high_water`) method.
+#[derive(Debug)]
+#[pin_project::pin_project]
+pub struct WithHighWater<Stream> {
+    #[pin]
+    inner: Stream,
+
+    peak: Arc<AtomicUsize>,
+}
+
+/// Read access to the high-water mark of a [`WithHighWater`].
+#[derive(Debug, Clone)]
+pub struct HighWaterHandle {
+    peak: Arc<AtomicUsize>,
+}
+
+impl<S> WithHighWater<S>
+where
+    S: Buffered,
+{
+    pub fn new(inner: S) -> (Self, HighWaterHandle) {
+        let peak = Arc::new(AtomicUsize::new(inner.buffered_len()));
+        let handle = HighWaterHandle { peak: peak.clone() };
+        (Self { inner, peak }, handle)
+    }
+}
+
+impl HighWaterHandle {
+    /// The largest number of items the wrapped stream has held so far.
+    pub fn peak(&self) -> usize {
+        self.peak.load(Ordering::Relaxed)
+    }
+}
+
+impl<S> Stream for WithHighWater<S>
+where
+    S: Stream + Buffered,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        let poll = this.inner.as_mut().poll_next(cx);
+        this.peak
+            .fetch_max(this.inner.buffered_len(), Ordering::Relaxed);
+        poll
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S> Buffered for WithHighWater<S>
+where
+    S: Buffered,
+{
+    fn buffered_len(&self) -> usize {
+        self.inner.buffered_len()
+    }
+}
+
+impl<S> WithHighWaterStreamExt for S where S: Stream + Buffered + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{channel::mpsc, poll, stream, StreamExt};
+
+    use super::*;
+    use crate::buffer_until::BufferUntilStreamExt;
+    use crate::chunks_strided::{ChunksStridedStreamExt, PartialWindow};
+    use crate::permit_gated::{GateBuffering, PermitGatedStreamExt};
+    use crate::ready_chunks_biased::ReadyChunksBiasedStreamExt;
+    use crate::ring::RingStreamExt;
+
+    #[tokio::test]
+    async fn the_peak_of_a_permit_gated_buffer() {
+        let (permit_tx, permit_rx) = mpsc::unbounded::<()>();
+        let (mut gated, high_water) = stream::iter([1, 2, 3, 4])
+            .permit_gated(permit_rx, GateBuffering::Fifo)
+            .with_high_water();
+        assert_eq!(high_water.peak(), 0);
+
+        assert_eq!(poll!(gated.next()), Poll::Pending);
+        assert_eq!(high_water.peak(), 4);
+
+        for _ in 0..4 {
+            permit_tx.unbounded_send(()).unwrap();
+        }
+        assert_eq!(
+            gated.by_ref().take(4).collect::<Vec<_>>().await,
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(gated.buffered_len(), 0);
+        assert_eq!(high_water.peak(), 4);
+    }
+
+    #[tokio::test]
+    async fn the_peak_is_sampled_between_the_polls() {
+        let (windows, high_water) = stream::iter(1..=10)
+            .chunks_strided(3, 2, PartialWindow::Drop)
+            .with_high_water();
+
+        // A full window is handed out within the poll, only the overlap is held afterwards.
+        assert_eq!(windows.count().await, 4);
+        assert_eq!(high_water.peak(), 1);
+    }
+
+    #[tokio::test]
+    async fn the_peak_of_a_pending_batch() {
+        let (tx, rx) = mpsc::unbounded();
+        let (mut batches, high_water) = rx.buffer_until(|batch| batch.len() == 3).with_high_water();
+
+        tx.unbounded_send(1).unwrap();
+        tx.unbounded_send(2).unwrap();
+        assert_eq!(poll!(batches.next()), Poll::Pending);
+        assert_eq!(high_water.peak(), 2);
+
+        tx.unbounded_send(3).unwrap();
+        assert_eq!(poll!(batches.next()), Poll::Ready(Some(vec![1, 2, 3])));
+        assert_eq!(batches.buffered_len(), 0);
+        assert_eq!(high_water.peak(), 2);
+    }
+
+    #[tokio::test]
+    async fn the_peak_of_a_ring_is_its_size() {
+        let (ring, _) = stream::iter(1..=5).ring(3);
+        let (ring, high_water) = ring.with_high_water();
+        assert_eq!(ring.count().await, 5);
+        assert_eq!(high_water.peak(), 3);
+    }
+
+    #[tokio::test]
+    async fn ready_chunks_are_never_held_between_the_polls() {
+        let (chunks, high_water) = stream::iter(1..=5).ready_chunks_biased(2).with_high_water();
+        assert_eq!(chunks.count().await, 3);
+        assert_eq!(high_water.peak(), 0);
+    }
+}
@@ -0,0 +1,171 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{stream::FusedStream, Stream};
+
+pub trait WithLatestFromStreamExt: Stream + Sized {
+    /// Pair each item with the most recent item of `right`, without waiting for `right` to yield a fresh one.
+    ///
+    /// `right` is polled once before each poll of `self`, so that an always-ready `right` cannot starve `self`;
+    /// a backlog of `right` is caught up with one item per poll. The items that arrive before
+    /// `right` has yielded anything are dropped. The stream ends when `self` ends, or when `right` ends
+    /// without having yielded anything.
+    fn with_latest_from<R>(self, right: R) -> WithLatestFrom<Self, R, R::Item>
+    where
+        R: Stream,
+        R::Item: Clone,
+    {
+        WithLatestFrom::new(self, right)
+    }
+}
+
+/// Stream for [`with_latest_from`](`WithLatestFromStreamExt::with_latest_from`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct WithLatestFrom<L, R, RI> {
+    #[pin]
+    left: L,
+    #[pin]
+    right: R,
+
+    latest_right: Option<RI>,
+    right_done: bool,
+    terminated: bool,
+}
+
+impl<L, R, RI> WithLatestFrom<L, R, RI> {
+    pub fn new(left: L, right: R) -> Self {
+        Self {
+            left,
+            right,
+            latest_right: None,
+            right_done: false,
+            terminated: false,
+        }
+    }
+}
+
+impl<L, R> Stream for WithLatestFrom<L, R, R::Item>
+where
+    L: Stream,
+    R: Stream,
+    R::Item: Clone,
+{
+    type Item = (L::Item, R::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        if *this.terminated {
+            return Poll::Ready(None);
+        }
+
+        Poll::Ready(loop {
+            if !*this.right_done {
+                match this.right.as_mut().poll_next(cx) {
+                    Poll::Pending => (),
+                    Poll::Ready(None) => *this.right_done = true,
+                    Poll::Ready(Some(item)) => *this.latest_right = Some(item),
+                }
+            }
+            if *this.right_done && this.latest_right.is_none() {
+                *this.terminated = true;
+                break None;
+            }
+
+            let Some(left) = ready!(this.left.as_mut().poll_next(cx)) else {
+                *this.terminated = true;
+                *this.latest_right = None;
+                break None;
+            };
+            if let Some(right) = this.latest_right.as_ref() {
+                break Some((left, right.clone()));
+            }
+        })
+    }
+}
+
+impl<L, R> FusedStream for WithLatestFrom<L, R, R::Item>
+where
+    L: Stream,
+    R: Stream,
+    R::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<S> WithLatestFromStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{channel::mpsc, poll, stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn left_sees_the_last_known_right_item() {
+        let (left_tx, left_rx) = mpsc::unbounded();
+        let (right_tx, right_rx) = mpsc::unbounded();
+        let mut sampled = left_rx.with_latest_from(right_rx);
+
+        left_tx.unbounded_send(1).unwrap();
+        assert_eq!(poll!(sampled.next()), Poll::Pending);
+
+        right_tx.unbounded_send('a').unwrap();
+        left_tx.unbounded_send(2).unwrap();
+        left_tx.unbounded_send(3).unwrap();
+        assert_eq!(poll!(sampled.next()), Poll::Ready(Some((2, 'a'))));
+        assert_eq!(poll!(sampled.next()), Poll::Ready(Some((3, 'a'))));
+
+        right_tx.unbounded_send('b').unwrap();
+        right_tx.unbounded_send('c').unwrap();
+        assert_eq!(poll!(sampled.next()), Poll::Pending);
+        left_tx.unbounded_send(4).unwrap();
+        assert_eq!(poll!(sampled.next()), Poll::Ready(Some((4, 'c'))));
+
+        drop(right_tx);
+        left_tx.unbounded_send(5).unwrap();
+        assert_eq!(poll!(sampled.next()), Poll::Ready(Some((5, 'c'))));
+
+        drop(left_tx);
+        assert_eq!(poll!(sampled.next()), Poll::Ready(None));
+        assert!(sampled.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn empty_right_ends_the_stream() {
+        assert!(stream::iter([1, 2, 3])
+            .with_latest_from(stream::empty::<()>())
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn right_is_not_awaited() {
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .with_latest_from(stream::iter(['a']).chain(stream::pending()))
+                .collect::<Vec<_>>()
+                .await,
+            vec![(1, 'a'), (2, 'a'), (3, 'a')]
+        );
+    }
+
+    #[tokio::test]
+    async fn an_always_ready_right_does_not_starve_left() {
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .with_latest_from(stream::iter(0..))
+                .collect::<Vec<_>>()
+                .await,
+            vec![(1, 0), (2, 1), (3, 2)]
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+use crate::interleave_snapshots::Mixed;
+
+pub trait WithPeriodicAggregateStreamExt: Stream + Sized {
+    /// Pass the items through, folding them into an aggregate and emitting `emit(&aggregate)` after every `every` items.
+    ///
+    /// The aggregate is reset to a clone of `init` after each snapshot. A trailing partial period yields no snapshot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `every` is zero.
+    fn with_periodic_aggregate<A, F, G, Sn>(
+        self,
+        every: usize,
+        init: A,
+        fold: F,
+        emit: G,
+    ) -> WithPeriodicAggregate<Self, A, F, G>
+    where
+        A: Clone,
+        F: FnMut(&mut A, &Self::Item),
+        G: FnMut(&A) -> Sn,
+    {
+        WithPeriodicAggregate::new(self, every, init, fold, emit)
+    }
+}
+
+/// Stream for [`with_periodic_aggregate`](`WithPeriodicAggregateStreamExt::with_periodic_aggregate`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct WithPeriodicAggregate<Stream, A, F, G> {
+    #[pin]
+    inner: Stream,
+    every: usize,
+    init: A,
+    fold: F,
+    emit: G,
+
+    aggregate: A,
+    since_snapshot: usize,
+}
+
+impl<S, A, F, G> WithPeriodicAggregate<S, A, F, G>
+where
+    A: Clone,
+{
+    pub fn new(inner: S, every: usize, init: A, fold: F, emit: G) -> Self {
+        assert!(every > 0, "the period must not be zero");
+        Self {
+            inner,
+            every,
+            aggregate: init.clone(),
+            init,
+            fold,
+            emit,
+            since_snapshot: 0,
+        }
+    }
+}
+
+impl<S, A, F, G, Sn> Stream for WithPeriodicAggregate<S, A, F, G>
+where
+    S: Stream,
+    A: Clone,
+    F: FnMut(&mut A, &S::Item),
+    G: FnMut(&A) -> Sn,
+{
+    type Item = Mixed<S::Item, Sn>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        if *this.since_snapshot == *this.every {
+            *this.since_snapshot = 0;
+            let snapshot = (this.emit)(this.aggregate);
+            *this.aggregate = this.init.clone();
+            return Poll::Ready(Some(Mixed::Snapshot(snapshot)));
+        }
+
+        let item = ready!(this.inner.poll_next(cx));
+        if let Some(item) = &item {
+            (this.fold)(this.aggregate, item);
+            *this.since_snapshot += 1;
+        }
+        Poll::Ready(item.map(Mixed::Data))
+    }
+}
+
+impl<S> WithPeriodicAggregateStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn snapshots_aggregate_each_period() {
+        assert_eq!(
+            stream::iter(1..=7)
+                .with_periodic_aggregate(3, 0, |sum, n| *sum += n, |sum| *sum)
+                .collect::<Vec<_>>()
+                .await,
+            vec![
+                Mixed::Data(1),
+                Mixed::Data(2),
+                Mixed::Data(3),
+                Mixed::Snapshot(6),
+                Mixed::Data(4),
+                Mixed::Data(5),
+                Mixed::Data(6),
+                Mixed::Snapshot(15),
+                Mixed::Data(7),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn the_aggregate_is_reset_after_each_snapshot() {
+        assert_eq!(
+            stream::iter(["a", "b", "c", "d"])
+                .with_periodic_aggregate(
+                    2,
+                    String::new(),
+                    |joined, s| joined.push_str(s),
+                    String::clone
+                )
+                .filter_map(|mixed| async move {
+                    match mixed {
+                        Mixed::Snapshot(joined) => Some(joined),
+                        Mixed::Data(_) => None,
+                    }
+                })
+                .collect::<Vec<_>>()
+                .await,
+            vec!["ab".to_owned(), "cd".to_owned()]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_period_is_rejected() {
+        let _ = stream::iter([1]).with_periodic_aggregate(0, 0, |_, _| (), |a| *a);
+    }
+}
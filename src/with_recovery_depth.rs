@@ -0,0 +1,123 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{Stream, TryStream};
+
+pub trait WithRecoveryDepthStreamExt: Stream + TryStream + Sized {
+    /// Tag each `Ok` item of a lenient fallible stream with the number of errors right before it.
+    ///
+    /// The errors themselves are passed through or dropped as per `errors`; either way they are counted.
+    fn with_recovery_depth(self, errors: DepthErrors) -> WithRecoveryDepth<Self> {
+        WithRecoveryDepth::new(self, errors)
+    }
+}
+
+/// What [`WithRecoveryDepth`] does with the upstream errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepthErrors {
+    /// Pass the errors through.
+    Emit,
+    /// Drop the errors, yielding only the tagged `Ok` items.
+    Swallow,
+}
+
+/// Stream for [`with_recovery_depth`](`WithRecoveryDepthStreamExt::with_recovery_depth`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct WithRecoveryDepth<Stream> {
+    #[pin]
+    inner: Stream,
+    errors: DepthErrors,
+
+    depth: usize,
+}
+
+impl<S> WithRecoveryDepth<S> {
+    pub fn new(inner: S, errors: DepthErrors) -> Self {
+        Self {
+            inner,
+            errors,
+            depth: 0,
+        }
+    }
+}
+
+impl<S> Stream for WithRecoveryDepth<S>
+where
+    S: Stream + TryStream,
+{
+    type Item = Result<(S::Ok, usize), S::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            match ready!(this.inner.as_mut().try_poll_next(cx)) {
+                None => break None,
+                Some(Ok(item)) => {
+                    let depth = std::mem::take(this.depth);
+                    break Some(Ok((item, depth)));
+                }
+                Some(Err(error)) => {
+                    *this.depth += 1;
+                    if *this.errors == DepthErrors::Emit {
+                        break Some(Err(error));
+                    }
+                }
+            }
+        })
+    }
+}
+
+impl<S> WithRecoveryDepthStreamExt for S where S: Stream + TryStream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    const ATTEMPTS: [Result<char, &str>; 7] = [
+        Ok('a'),
+        Err("timeout"),
+        Err("timeout"),
+        Ok('b'),
+        Ok('c'),
+        Err("refused"),
+        Ok('d'),
+    ];
+
+    #[tokio::test]
+    async fn errors_are_counted_and_emitted() {
+        assert_eq!(
+            stream::iter(ATTEMPTS)
+                .with_recovery_depth(DepthErrors::Emit)
+                .collect::<Vec<_>>()
+                .await,
+            vec![
+                Ok(('a', 0)),
+                Err("timeout"),
+                Err("timeout"),
+                Ok(('b', 2)),
+                Ok(('c', 0)),
+                Err("refused"),
+                Ok(('d', 1)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn errors_are_counted_and_swallowed() {
+        assert_eq!(
+            stream::iter(ATTEMPTS)
+                .with_recovery_depth(DepthErrors::Swallow)
+                .collect::<Vec<_>>()
+                .await,
+            vec![Ok(('a', 0)), Ok(('b', 2)), Ok(('c', 0)), Ok(('d', 1))]
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{stream::FusedStream, Stream, TryStream};
+
+pub trait WithTerminationMarkerStreamExt: Stream + TryStream + Sized {
+    /// Wrap the items of a fallible stream into [`Event`]s, making its end explicit.
+    ///
+    /// A clean end is marked with [`Event::Ended`]; the first error ends the stream as [`Event::Failed`].
+    fn with_termination_marker(self) -> WithTerminationMarker<Self> {
+        WithTerminationMarker::new(self)
+    }
+}
+
+/// An item of [`WithTerminationMarker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Event<T, E> {
+    /// An upstream `Ok` item.
+    Item(T),
+    /// The upstream has ended cleanly; this is the last event.
+    Ended,
+    /// The upstream has failed with this error; this is the last event.
+    Failed(E),
+}
+
+/// Stream for [`with_termination_marker`](`WithTerminationMarkerStreamExt::with_termination_marker`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct WithTerminationMarker<Stream> {
+    #[pin]
+    inner: Stream,
+
+    terminated: bool,
+}
+
+impl<S> WithTerminationMarker<S> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            terminated: false,
+        }
+    }
+}
+
+impl<S> Stream for WithTerminationMarker<S>
+where
+    S: Stream + TryStream,
+{
+    type Item = Event<S::Ok, S::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        if *this.terminated {
+            return Poll::Ready(None);
+        }
+
+        Poll::Ready(Some(match ready!(this.inner.try_poll_next(cx)) {
+            Some(Ok(item)) => Event::Item(item),
+            None => {
+                *this.terminated = true;
+                Event::Ended
+            }
+            Some(Err(error)) => {
+                *this.terminated = true;
+                Event::Failed(error)
+            }
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.terminated {
+            return (0, Some(0));
+        }
+        // Any upstream item may be the terminating error, but some event is always due.
+        let (_, upper) = self.inner.size_hint();
+        (1, upper.and_then(|upper| upper.checked_add(1)))
+    }
+}
+
+impl<S> FusedStream for WithTerminationMarker<S>
+where
+    S: Stream + TryStream,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<S> WithTerminationMarkerStreamExt for S where S: Stream + TryStream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn a_clean_end_is_marked() {
+        assert_eq!(
+            stream::iter([Ok::<_, ()>(1), Ok(2)])
+                .with_termination_marker()
+                .collect::<Vec<_>>()
+                .await,
+            vec![Event::Item(1), Event::Item(2), Event::Ended]
+        );
+    }
+
+    #[tokio::test]
+    async fn an_error_ends_the_stream() {
+        assert_eq!(
+            stream::iter([Ok(1), Err("broken"), Ok(3)])
+                .with_termination_marker()
+                .collect::<Vec<_>>()
+                .await,
+            vec![Event::Item(1), Event::Failed("broken")]
+        );
+    }
+
+    #[tokio::test]
+    async fn an_empty_stream_is_marked_too() {
+        assert_eq!(
+            stream::empty::<Result<(), ()>>()
+                .with_termination_marker()
+                .collect::<Vec<_>>()
+                .await,
+            vec![Event::Ended]
+        );
+    }
+}
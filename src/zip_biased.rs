@@ -3,7 +3,7 @@ use std::{
     task::{Context, Poll},
 };
 
-use futures::{Stream, TryStream};
+use futures::{future::Either, stream::FusedStream, Stream, TryStream};
 
 pub trait ZipBiasedStreamExt: Stream + Sized {
     fn zip_biased<R>(self, right: R) -> ZipBiased<Self, R, Self::Item>
@@ -12,6 +12,57 @@ pub trait ZipBiasedStreamExt: Stream + Sized {
     {
         ZipBiased::new(self, right)
     }
+
+    /// Similar to [`zip_biased`](`ZipBiasedStreamExt::zip_biased`), but for three streams yielding flat triples.
+    ///
+    /// `self` drives: once its item is ready, `b` and then `c` are polled for one item each.
+    /// The stream ends as soon as any of the three does.
+    fn zip3_biased<B, C>(self, b: B, c: C) -> Zip3Biased<Self, B, C, Self::Item, B::Item>
+    where
+        B: Stream,
+        C: Stream,
+    {
+        Zip3Biased::new(self, b, c)
+    }
+
+    /// Similar to [`zip_biased`](`ZipBiasedStreamExt::zip_biased`), but samples one item from each of `rights`
+    /// per left item, yielding `(left, samples)` with the samples in the order of `rights`.
+    ///
+    /// The rights are polled one after another; the samples gathered so far are kept while one of them is pending.
+    /// The stream ends as soon as the left or any of the rights does.
+    ///
+    /// Each yielded `Vec` is handed over to the caller, so a new one is allocated per left item: the buffer
+    /// is reused only while the samples of a single left item are gathered, across the polls that it takes.
+    fn zip_biased_all<R>(self, rights: Vec<R>) -> ZipBiasedAll<Self, R, Self::Item, R::Item>
+    where
+        R: Stream,
+    {
+        ZipBiasedAll::new(self, rights)
+    }
+
+    /// Similar to [`zip_biased`](`ZipBiasedStreamExt::zip_biased`), but driven by the right side:
+    /// a ready right item is held until a left item is pulled to pair with it.
+    ///
+    /// The pairs are still `(left, right)`.
+    fn zip_biased_right<R>(self, right: R) -> ZipBiasedRight<Self, R, R::Item>
+    where
+        R: Stream,
+    {
+        ZipBiasedRight::new(self, right)
+    }
+
+    /// Similar to [`zip_biased`](`ZipBiasedStreamExt::zip_biased`), but once either side ends,
+    /// the leftovers of the other side are drained into `finish` instead of being dropped.
+    ///
+    /// A left item that has been pulled but not paired yet is passed to `finish` too.
+    /// The stream ends after the longer side is drained, so it never ends if that side is infinite.
+    fn zip_biased_finish<R, F>(self, right: R, finish: F) -> ZipBiasedFinish<Self, R, Self::Item, F>
+    where
+        R: Stream,
+        F: FnMut(Either<Self::Item, R::Item>),
+    {
+        ZipBiasedFinish::new(self, right, finish)
+    }
 }
 
 pub trait TryZipBiasedStreamExt: Stream + TryStream + Sized {
@@ -30,6 +81,7 @@ pub struct ZipBiased<L, R, LI> {
     left: L,
     #[pin]
     right: R,
+    terminated: bool,
 
     left_poll: Poll<Option<LI>>,
 }
@@ -41,8 +93,73 @@ pub struct TryZipBiased<L, R, LI> {
     left: L,
     #[pin]
     right: R,
+    terminated: bool,
+
+    left_poll: Poll<Option<LI>>,
+}
+
+/// Stream for [`zip_biased_right`](`ZipBiasedStreamExt::zip_biased_right`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct ZipBiasedRight<L, R, RI> {
+    #[pin]
+    left: L,
+    #[pin]
+    right: R,
+    terminated: bool,
+
+    right_poll: Poll<Option<RI>>,
+}
+
+/// Stream for [`zip3_biased`](`ZipBiasedStreamExt::zip3_biased`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct Zip3Biased<A, B, C, AI, BI> {
+    #[pin]
+    a: A,
+    #[pin]
+    b: B,
+    #[pin]
+    c: C,
+    terminated: bool,
+
+    a_poll: Poll<Option<AI>>,
+    b_poll: Poll<Option<BI>>,
+}
+
+/// Stream for [`zip_biased_all`](`ZipBiasedStreamExt::zip_biased_all`) method.
+#[derive(Debug)]
+#[pin_project::pin_project]
+pub struct ZipBiasedAll<L, R, LI, RI> {
+    #[pin]
+    left: L,
+    rights: Vec<Pin<Box<R>>>,
+    terminated: bool,
 
     left_poll: Poll<Option<LI>>,
+    samples: Vec<RI>,
+}
+
+/// Stream for [`zip_biased_finish`](`ZipBiasedStreamExt::zip_biased_finish`) method.
+#[derive(Debug, Clone, Copy)]
+#[pin_project::pin_project]
+pub struct ZipBiasedFinish<L, R, LI, F> {
+    #[pin]
+    left: L,
+    #[pin]
+    right: R,
+    finish: F,
+
+    left_poll: Poll<Option<LI>>,
+    phase: FinishPhase,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum FinishPhase {
+    Zipping,
+    DrainingLeft,
+    DrainingRight,
+    Done,
 }
 
 impl<L, R, LI> ZipBiased<L, R, LI> {
@@ -50,9 +167,48 @@ impl<L, R, LI> ZipBiased<L, R, LI> {
         Self {
             left,
             right,
+            terminated: false,
             left_poll: Poll::Pending,
         }
     }
+
+    /// The left item pulled from the upstream that is waiting for its right counterpart, if any.
+    pub fn buffered_left(&self) -> Option<&LI> {
+        match &self.left_poll {
+            Poll::Ready(Some(left)) => Some(left),
+            _ => None,
+        }
+    }
+
+    /// Acquires references to the underlying streams.
+    pub fn get_ref(&self) -> (&L, &R) {
+        (&self.left, &self.right)
+    }
+
+    /// Acquires mutable references to the underlying streams.
+    ///
+    /// Polling the underlying streams directly may confuse this adapter.
+    pub fn get_mut(&mut self) -> (&mut L, &mut R) {
+        (&mut self.left, &mut self.right)
+    }
+
+    /// Acquires pinned mutable references to the underlying streams.
+    ///
+    /// Polling the underlying streams directly may confuse this adapter.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> (Pin<&mut L>, Pin<&mut R>) {
+        let this = self.project();
+        (this.left, this.right)
+    }
+
+    /// Consumes this adapter, returning the underlying streams
+    /// together with the [buffered left item](`ZipBiased::buffered_left`), so that it is not lost.
+    pub fn into_inner(self) -> (L, R, Option<LI>) {
+        let buffered = match self.left_poll {
+            Poll::Ready(left) => left,
+            Poll::Pending => None,
+        };
+        (self.left, self.right, buffered)
+    }
 }
 
 impl<L, R, LI> TryZipBiased<L, R, LI> {
@@ -60,9 +216,75 @@ impl<L, R, LI> TryZipBiased<L, R, LI> {
         Self {
             left,
             right,
+            terminated: false,
             left_poll: Poll::Pending,
         }
     }
+
+    /// The left item pulled from the upstream that is waiting for its right counterpart, if any.
+    pub fn buffered_left(&self) -> Option<&LI> {
+        match &self.left_poll {
+            Poll::Ready(Some(left)) => Some(left),
+            _ => None,
+        }
+    }
+}
+
+impl<L, R, RI> ZipBiasedRight<L, R, RI> {
+    pub fn new(left: L, right: R) -> Self {
+        Self {
+            left,
+            right,
+            terminated: false,
+            right_poll: Poll::Pending,
+        }
+    }
+
+    /// The right item pulled from the upstream that is waiting for its left counterpart, if any.
+    pub fn buffered_right(&self) -> Option<&RI> {
+        match &self.right_poll {
+            Poll::Ready(Some(right)) => Some(right),
+            _ => None,
+        }
+    }
+}
+
+impl<A, B, C, AI, BI> Zip3Biased<A, B, C, AI, BI> {
+    pub fn new(a: A, b: B, c: C) -> Self {
+        Self {
+            a,
+            b,
+            c,
+            terminated: false,
+            a_poll: Poll::Pending,
+            b_poll: Poll::Pending,
+        }
+    }
+}
+
+impl<L, R, LI, RI> ZipBiasedAll<L, R, LI, RI> {
+    pub fn new(left: L, rights: Vec<R>) -> Self {
+        let samples = Vec::with_capacity(rights.len());
+        Self {
+            left,
+            rights: rights.into_iter().map(Box::pin).collect(),
+            terminated: false,
+            left_poll: Poll::Pending,
+            samples,
+        }
+    }
+}
+
+impl<L, R, LI, F> ZipBiasedFinish<L, R, LI, F> {
+    pub fn new(left: L, right: R, finish: F) -> Self {
+        Self {
+            left,
+            right,
+            finish,
+            left_poll: Poll::Pending,
+            phase: FinishPhase::Zipping,
+        }
+    }
 }
 
 impl<L, R> Stream for ZipBiased<L, R, L::Item>
@@ -75,6 +297,10 @@ where
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         use std::task::ready;
 
+        if self.terminated {
+            return Poll::Ready(None);
+        }
+
         let mut this = self.project();
 
         Poll::Ready(loop {
@@ -82,16 +308,195 @@ where
                 Poll::Pending => {
                     *this.left_poll = Poll::Ready(ready!(this.left.as_mut().poll_next(cx)))
                 }
-                Poll::Ready(None) => break None,
+                Poll::Ready(None) => {
+                    *this.terminated = true;
+                    break None;
+                }
                 Poll::Ready(some_left @ Some(_)) => {
                     let right_opt = ready!(this.right.as_mut().poll_next(cx));
                     let some_left = some_left.take();
                     *this.left_poll = Poll::Pending;
+                    *this.terminated = right_opt.is_none();
                     break some_left.zip(right_opt);
                 }
             }
         })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.terminated {
+            return (0, Some(0));
+        }
+
+        let (left_lower, left_upper) = match &self.left_poll {
+            Poll::Pending => self.left.size_hint(),
+            Poll::Ready(None) => (0, Some(0)),
+            Poll::Ready(Some(_)) => {
+                let (lower, upper) = self.left.size_hint();
+                (
+                    lower.saturating_add(1),
+                    upper.and_then(|upper| upper.checked_add(1)),
+                )
+            }
+        };
+        let (right_lower, right_upper) = self.right.size_hint();
+
+        let upper = match (left_upper, right_upper) {
+            (Some(left), Some(right)) => Some(left.min(right)),
+            (upper @ Some(_), None) | (None, upper) => upper,
+        };
+        (left_lower.min(right_lower), upper)
+    }
+}
+
+impl<L, R> Stream for ZipBiasedRight<L, R, R::Item>
+where
+    L: Stream,
+    R: Stream,
+{
+    type Item = (L::Item, R::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        if self.terminated {
+            return Poll::Ready(None);
+        }
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            match this.right_poll {
+                Poll::Pending => {
+                    *this.right_poll = Poll::Ready(ready!(this.right.as_mut().poll_next(cx)))
+                }
+                Poll::Ready(None) => {
+                    *this.terminated = true;
+                    break None;
+                }
+                Poll::Ready(some_right @ Some(_)) => {
+                    let left_opt = ready!(this.left.as_mut().poll_next(cx));
+                    let some_right = some_right.take();
+                    *this.right_poll = Poll::Pending;
+                    *this.terminated = left_opt.is_none();
+                    break left_opt.zip(some_right);
+                }
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.terminated {
+            return (0, Some(0));
+        }
+
+        let (right_lower, right_upper) = match &self.right_poll {
+            Poll::Pending => self.right.size_hint(),
+            Poll::Ready(None) => (0, Some(0)),
+            Poll::Ready(Some(_)) => {
+                let (lower, upper) = self.right.size_hint();
+                (
+                    lower.saturating_add(1),
+                    upper.and_then(|upper| upper.checked_add(1)),
+                )
+            }
+        };
+        let (left_lower, left_upper) = self.left.size_hint();
+
+        let upper = match (left_upper, right_upper) {
+            (Some(left), Some(right)) => Some(left.min(right)),
+            (upper @ Some(_), None) | (None, upper) => upper,
+        };
+        (left_lower.min(right_lower), upper)
+    }
+}
+
+impl<A, B, C> Stream for Zip3Biased<A, B, C, A::Item, B::Item>
+where
+    A: Stream,
+    B: Stream,
+    C: Stream,
+{
+    type Item = (A::Item, B::Item, C::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        if self.terminated {
+            return Poll::Ready(None);
+        }
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            match (&mut *this.a_poll, &mut *this.b_poll) {
+                (Poll::Pending, _) => {
+                    *this.a_poll = Poll::Ready(ready!(this.a.as_mut().poll_next(cx)))
+                }
+                (Poll::Ready(None), _) | (_, Poll::Ready(None)) => {
+                    *this.terminated = true;
+                    break None;
+                }
+                (Poll::Ready(Some(_)), Poll::Pending) => {
+                    *this.b_poll = Poll::Ready(ready!(this.b.as_mut().poll_next(cx)))
+                }
+                (Poll::Ready(some_a @ Some(_)), Poll::Ready(some_b @ Some(_))) => {
+                    let c_opt = ready!(this.c.as_mut().poll_next(cx));
+                    let some_ab = some_a.take().zip(some_b.take());
+                    *this.a_poll = Poll::Pending;
+                    *this.b_poll = Poll::Pending;
+                    *this.terminated = c_opt.is_none();
+                    break some_ab.zip(c_opt).map(|((a, b), c)| (a, b, c));
+                }
+            }
+        })
+    }
+}
+
+impl<L, R> Stream for ZipBiasedAll<L, R, L::Item, R::Item>
+where
+    L: Stream,
+    R: Stream,
+{
+    type Item = (L::Item, Vec<R::Item>);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        if self.terminated {
+            return Poll::Ready(None);
+        }
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            match this.left_poll {
+                Poll::Pending => {
+                    *this.left_poll = Poll::Ready(ready!(this.left.as_mut().poll_next(cx)))
+                }
+                Poll::Ready(None) => {
+                    *this.terminated = true;
+                    break None;
+                }
+                Poll::Ready(some_left @ Some(_)) => {
+                    while let Some(right) = this.rights.get_mut(this.samples.len()) {
+                        let Some(sample) = ready!(right.as_mut().poll_next(cx)) else {
+                            *this.terminated = true;
+                            *this.left_poll = Poll::Pending;
+                            this.samples.clear();
+                            return Poll::Ready(None);
+                        };
+                        this.samples.push(sample);
+                    }
+                    let some_left = some_left.take();
+                    *this.left_poll = Poll::Pending;
+                    let samples =
+                        std::mem::replace(this.samples, Vec::with_capacity(this.rights.len()));
+                    break some_left.map(|left| (left, samples));
+                }
+            }
+        })
+    }
 }
 
 impl<L, R> Stream for TryZipBiased<L, R, L::Ok>
@@ -106,6 +511,10 @@ where
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         use std::task::ready;
 
+        if self.terminated {
+            return Poll::Ready(None);
+        }
+
         let mut this = self.project();
 
         Poll::Ready(loop {
@@ -114,11 +523,19 @@ where
                     Err(reason) => break Some(Err(reason)),
                     Ok(left_opt) => *this.left_poll = Poll::Ready(left_opt),
                 },
-                Poll::Ready(None) => break None,
+                Poll::Ready(None) => {
+                    *this.terminated = true;
+                    break None;
+                }
                 Poll::Ready(some_left @ Some(_)) => {
                     match ready!(this.right.as_mut().poll_next(cx)).transpose() {
                         Err(reason) => break Some(Err(reason)),
-                        Ok(right_opt) => break some_left.take().zip(right_opt).map(Ok),
+                        Ok(right_opt) => {
+                            let some_left = some_left.take();
+                            *this.left_poll = Poll::Pending;
+                            *this.terminated = right_opt.is_none();
+                            break some_left.zip(right_opt).map(Ok);
+                        }
                     }
                 }
             }
@@ -126,15 +543,119 @@ where
     }
 }
 
+impl<L, R> FusedStream for ZipBiased<L, R, L::Item>
+where
+    L: Stream,
+    R: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<L, R> FusedStream for ZipBiasedRight<L, R, R::Item>
+where
+    L: Stream,
+    R: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<A, B, C> FusedStream for Zip3Biased<A, B, C, A::Item, B::Item>
+where
+    A: Stream,
+    B: Stream,
+    C: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<L, R> FusedStream for ZipBiasedAll<L, R, L::Item, R::Item>
+where
+    L: Stream,
+    R: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<L, R> FusedStream for TryZipBiased<L, R, L::Ok>
+where
+    L: Stream + TryStream,
+    R: Stream + TryStream<Error = L::Error>,
+    L: Stream<Item = Result<L::Ok, L::Error>>,
+    R: Stream<Item = Result<R::Ok, L::Error>>,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<L, R, F> Stream for ZipBiasedFinish<L, R, L::Item, F>
+where
+    L: Stream,
+    R: Stream,
+    F: FnMut(Either<L::Item, R::Item>),
+{
+    type Item = (L::Item, R::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            match this.phase {
+                FinishPhase::Zipping => match this.left_poll {
+                    Poll::Pending => {
+                        *this.left_poll = Poll::Ready(ready!(this.left.as_mut().poll_next(cx)))
+                    }
+                    Poll::Ready(None) => *this.phase = FinishPhase::DrainingRight,
+                    Poll::Ready(some_left @ Some(_)) => {
+                        let right_opt = ready!(this.right.as_mut().poll_next(cx));
+                        let left = some_left.take().expect("matched as Some");
+                        *this.left_poll = Poll::Pending;
+                        match right_opt {
+                            Some(right) => break Some((left, right)),
+                            None => {
+                                (this.finish)(Either::Left(left));
+                                *this.phase = FinishPhase::DrainingLeft;
+                            }
+                        }
+                    }
+                },
+                FinishPhase::DrainingLeft => match ready!(this.left.as_mut().poll_next(cx)) {
+                    Some(left) => (this.finish)(Either::Left(left)),
+                    None => *this.phase = FinishPhase::Done,
+                },
+                FinishPhase::DrainingRight => match ready!(this.right.as_mut().poll_next(cx)) {
+                    Some(right) => (this.finish)(Either::Right(right)),
+                    None => *this.phase = FinishPhase::Done,
+                },
+                FinishPhase::Done => break None,
+            }
+        })
+    }
+}
+
 impl<L> ZipBiasedStreamExt for L where L: Stream + Sized {}
 impl<L> TryZipBiasedStreamExt for L where L: Stream + TryStream + Sized {}
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::Ordering;
+
+    use futures::channel::mpsc;
     use futures::stream;
-    use futures::StreamExt;
+    use futures::{poll, StreamExt};
 
     use super::*;
+    use crate::test_utils::{counting_polls, ready_after_n_polls};
 
     #[tokio::test]
     async fn left_empty() {
@@ -151,4 +672,392 @@ mod tests {
 
         assert_eq!(left.zip_biased(right).collect::<Vec<_>>().await, vec![]);
     }
+
+    #[tokio::test]
+    async fn right_biased_left_empty() {
+        let left = stream::empty::<()>();
+        let right = stream::repeat(());
+
+        assert_eq!(
+            left.zip_biased_right(right).collect::<Vec<_>>().await,
+            vec![]
+        );
+    }
+
+    #[tokio::test]
+    async fn right_biased_right_empty() {
+        let left = stream::repeat(());
+        let right = stream::empty::<()>();
+
+        assert_eq!(
+            left.zip_biased_right(right).collect::<Vec<_>>().await,
+            vec![]
+        );
+    }
+
+    #[tokio::test]
+    async fn right_biased_holds_the_right_item_until_left_is_ready() {
+        let (left_tx, left_rx) = mpsc::unbounded();
+        let (right, right_polls) = counting_polls(stream::iter(['a', 'b']));
+        let mut zipped = left_rx.zip_biased_right(right);
+
+        assert_eq!(poll!(zipped.next()), Poll::Pending);
+        assert_eq!(zipped.buffered_right(), Some(&'a'));
+        assert_eq!(poll!(zipped.next()), Poll::Pending);
+        assert_eq!(right_polls.load(Ordering::Relaxed), 1);
+
+        left_tx.unbounded_send(1).unwrap();
+        assert_eq!(poll!(zipped.next()), Poll::Ready(Some((1, 'a'))));
+        left_tx.unbounded_send(2).unwrap();
+        assert_eq!(poll!(zipped.next()), Poll::Ready(Some((2, 'b'))));
+        assert_eq!(poll!(zipped.next()), Poll::Ready(None));
+        assert!(zipped.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn zip3_first_is_the_shortest() {
+        let (c, c_polls) = counting_polls(stream::iter(["x", "y", "z"]));
+        let mut zipped = stream::iter([1, 2]).zip3_biased(stream::iter(['a', 'b', 'c']), c);
+
+        assert_eq!(zipped.next().await, Some((1, 'a', "x")));
+        assert_eq!(zipped.next().await, Some((2, 'b', "y")));
+        assert_eq!(zipped.next().await, None);
+        assert!(zipped.is_terminated());
+        assert_eq!(c_polls.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn zip3_second_is_the_shortest() {
+        let (c, c_polls) = counting_polls(stream::iter(["x", "y", "z"]));
+        let mut zipped = stream::iter([1, 2, 3]).zip3_biased(stream::iter(['a']), c);
+
+        assert_eq!(zipped.next().await, Some((1, 'a', "x")));
+        assert_eq!(zipped.next().await, None);
+        assert!(zipped.is_terminated());
+        assert_eq!(c_polls.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn zip3_third_is_the_shortest() {
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .zip3_biased(stream::iter(['a', 'b', 'c']), stream::iter(["x"]))
+                .collect::<Vec<_>>()
+                .await,
+            vec![(1, 'a', "x")]
+        );
+    }
+
+    #[tokio::test]
+    async fn zip3_samples_b_and_c_only_once_a_is_ready() {
+        let (a_tx, a_rx) = mpsc::unbounded();
+        let (b, b_polls) = counting_polls(stream::iter(['a', 'b']));
+        let (c, c_polls) = counting_polls(stream::iter(["x", "y"]));
+        let mut zipped = a_rx.zip3_biased(b, c);
+
+        assert_eq!(poll!(zipped.next()), Poll::Pending);
+        assert_eq!(b_polls.load(Ordering::Relaxed), 0);
+        assert_eq!(c_polls.load(Ordering::Relaxed), 0);
+
+        a_tx.unbounded_send(1).unwrap();
+        assert_eq!(poll!(zipped.next()), Poll::Ready(Some((1, 'a', "x"))));
+        assert_eq!(poll!(zipped.next()), Poll::Pending);
+        assert_eq!(b_polls.load(Ordering::Relaxed), 1);
+        assert_eq!(c_polls.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn zip_all_ends_with_the_shortest_right() {
+        let mut zipped = stream::iter([1, 2, 3, 4]).zip_biased_all(vec![
+            stream::iter(vec!['a', 'b', 'c']),
+            stream::iter(vec!['x', 'y']),
+            stream::iter(vec!['p', 'q', 'r', 's', 't']),
+        ]);
+
+        assert_eq!(zipped.next().await, Some((1, vec!['a', 'x', 'p'])));
+        assert_eq!(zipped.next().await, Some((2, vec!['b', 'y', 'q'])));
+        assert_eq!(zipped.next().await, None);
+        assert!(zipped.is_terminated());
+        assert_eq!(zipped.next().await, None);
+    }
+
+    #[tokio::test]
+    async fn zip_all_keeps_the_samples_while_a_right_is_pending() {
+        let (tx, rx) = mpsc::unbounded();
+        let (first, first_polls) = counting_polls(stream::iter([10, 20]));
+        let mut zipped = stream::iter([1, 2]).zip_biased_all(vec![first.boxed(), rx.boxed()]);
+
+        assert_eq!(poll!(zipped.next()), Poll::Pending);
+        assert_eq!(poll!(zipped.next()), Poll::Pending);
+        assert_eq!(first_polls.load(Ordering::Relaxed), 1);
+
+        tx.unbounded_send(100).unwrap();
+        assert_eq!(poll!(zipped.next()), Poll::Ready(Some((1, vec![10, 100]))));
+    }
+
+    #[tokio::test]
+    async fn finish_observes_left_leftovers() {
+        let left = stream::iter([1, 2, 3]);
+        let right = stream::iter(['a']);
+
+        let (mut lefts, mut rights) = (vec![], vec![]);
+        let pairs = left
+            .zip_biased_finish(right, |leftover| match leftover {
+                Either::Left(left) => lefts.push(left),
+                Either::Right(right) => rights.push(right),
+            })
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(pairs, vec![(1, 'a')]);
+        assert_eq!(lefts, vec![2, 3]);
+        assert_eq!(rights, vec![]);
+    }
+
+    #[tokio::test]
+    async fn finish_observes_right_leftovers() {
+        let left = stream::iter([1]);
+        let right = stream::iter(['a', 'b', 'c']);
+
+        let (mut lefts, mut rights) = (vec![], vec![]);
+        let pairs = left
+            .zip_biased_finish(right, |leftover| match leftover {
+                Either::Left(left) => lefts.push(left),
+                Either::Right(right) => rights.push(right),
+            })
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(pairs, vec![(1, 'a')]);
+        assert_eq!(lefts, vec![]);
+        assert_eq!(rights, vec!['b', 'c']);
+    }
+
+    #[tokio::test]
+    async fn finish_is_not_called_for_equal_lengths() {
+        let left = stream::iter([1, 2]);
+        let right = stream::iter(['a', 'b']);
+
+        let (mut lefts, mut rights) = (vec![], vec![]);
+        let pairs = left
+            .zip_biased_finish(right, |leftover| match leftover {
+                Either::Left(left) => lefts.push(left),
+                Either::Right(right) => rights.push(right),
+            })
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(pairs, vec![(1, 'a'), (2, 'b')]);
+        assert_eq!(lefts, vec![]);
+        assert_eq!(rights, vec![]);
+    }
+
+    #[tokio::test]
+    async fn buffered_left_reflects_the_held_item() {
+        let (right_tx, right_rx) = mpsc::unbounded();
+        let mut zipped = stream::iter([1, 2]).zip_biased(right_rx);
+
+        assert_eq!(zipped.buffered_left(), None);
+        assert_eq!(poll!(zipped.next()), Poll::Pending);
+        assert_eq!(zipped.buffered_left(), Some(&1));
+
+        right_tx.unbounded_send('a').unwrap();
+        assert_eq!(poll!(zipped.next()), Poll::Ready(Some((1, 'a'))));
+        assert_eq!(zipped.buffered_left(), None);
+
+        assert_eq!(poll!(zipped.next()), Poll::Pending);
+        assert_eq!(zipped.buffered_left(), Some(&2));
+    }
+
+    #[tokio::test]
+    async fn into_inner_returns_the_buffered_left_item() {
+        let (right_tx, right_rx) = mpsc::unbounded();
+        let mut zipped = stream::iter([1, 2, 3]).zip_biased(right_rx);
+
+        right_tx.unbounded_send('a').unwrap();
+        assert_eq!(zipped.next().await, Some((1, 'a')));
+        assert_eq!(poll!(zipped.next()), Poll::Pending);
+        assert_eq!(zipped.get_ref().0.size_hint(), (1, Some(1)));
+
+        let (left, right, buffered) = zipped.into_inner();
+        assert_eq!(buffered, Some(2));
+        assert_eq!(left.collect::<Vec<_>>().await, vec![3]);
+
+        right_tx.unbounded_send('b').unwrap();
+        drop(right_tx);
+        assert_eq!(right.collect::<Vec<_>>().await, vec!['b']);
+    }
+
+    #[tokio::test]
+    async fn into_inner_without_a_buffered_left_item() {
+        let mut zipped = stream::iter([1, 2]).zip_biased(stream::iter(['a', 'b']));
+        assert_eq!(zipped.next().await, Some((1, 'a')));
+
+        let (left, right) = zipped.get_mut();
+        assert_eq!(left.size_hint(), (1, Some(1)));
+        assert_eq!(right.size_hint(), (1, Some(1)));
+        let (left, right, buffered) = zipped.into_inner();
+        assert_eq!(buffered, None);
+        assert_eq!(left.zip(right).collect::<Vec<_>>().await, vec![(2, 'b')]);
+    }
+
+    #[tokio::test]
+    async fn try_buffered_left_reflects_the_held_item() {
+        let (right_tx, right_rx) = mpsc::unbounded::<Result<char, ()>>();
+        let mut zipped = stream::iter([Ok(1)]).try_zip_biased(right_rx);
+
+        assert_eq!(zipped.buffered_left(), None);
+        assert_eq!(poll!(zipped.next()), Poll::Pending);
+        assert_eq!(zipped.buffered_left(), Some(&1));
+
+        right_tx.unbounded_send(Ok('a')).unwrap();
+        assert_eq!(poll!(zipped.next()), Poll::Ready(Some(Ok((1, 'a')))));
+        assert_eq!(zipped.buffered_left(), None);
+    }
+
+    #[tokio::test]
+    async fn right_is_not_polled_while_left_is_pending() {
+        let left = stream::iter(1..=3).then(|x| ready_after_n_polls(x, 2));
+        let (right, right_polls) = counting_polls(stream::iter(['a', 'b', 'c', 'd']));
+
+        assert_eq!(
+            left.zip_biased(right).collect::<Vec<_>>().await,
+            vec![(1, 'a'), (2, 'b'), (3, 'c')]
+        );
+        assert_eq!(right_polls.load(Ordering::Relaxed), 3);
+    }
+
+    #[tokio::test]
+    async fn left_is_not_polled_while_right_is_pending() {
+        let (left, left_polls) = counting_polls(stream::iter([1, 2, 3]));
+        let (right, right_polls) = counting_polls(
+            stream::iter(['a', 'b'])
+                .then(|x| ready_after_n_polls(x, 1))
+                .boxed(),
+        );
+
+        assert_eq!(
+            left.zip_biased(right).collect::<Vec<_>>().await,
+            vec![(1, 'a'), (2, 'b')]
+        );
+        assert_eq!(left_polls.load(Ordering::Relaxed), 3);
+        assert_eq!(right_polls.load(Ordering::Relaxed), 2 + 2 + 1);
+    }
+
+    #[tokio::test]
+    async fn fused_when_left_ends_first() {
+        let (right, right_polls) = counting_polls(stream::iter(['a', 'b', 'c']));
+        let mut zipped = stream::iter([1, 2]).zip_biased(right);
+
+        assert!(!zipped.is_terminated());
+        assert_eq!(zipped.next().await, Some((1, 'a')));
+        assert_eq!(zipped.next().await, Some((2, 'b')));
+        assert_eq!(zipped.next().await, None);
+        assert!(zipped.is_terminated());
+        assert_eq!(zipped.next().await, None);
+        assert_eq!(right_polls.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn fused_when_right_ends_first() {
+        let (left, left_polls) = counting_polls(stream::iter([1, 2, 3]));
+        let (right, right_polls) = counting_polls(stream::iter(['a']));
+        let mut zipped = left.zip_biased(right);
+
+        assert_eq!(zipped.next().await, Some((1, 'a')));
+        assert!(!zipped.is_terminated());
+        assert_eq!(zipped.next().await, None);
+        assert!(zipped.is_terminated());
+        assert_eq!(zipped.next().await, None);
+        assert_eq!(zipped.buffered_left(), None);
+        assert_eq!(left_polls.load(Ordering::Relaxed), 2);
+        assert_eq!(right_polls.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn try_fused_when_left_ends_first() {
+        let (right, right_polls) =
+            counting_polls(stream::iter([Ok::<_, ()>('a'), Ok('b'), Ok('c')]));
+        let mut zipped = stream::iter([Ok(1), Ok(2)]).try_zip_biased(right);
+
+        assert_eq!(zipped.next().await, Some(Ok((1, 'a'))));
+        assert_eq!(zipped.next().await, Some(Ok((2, 'b'))));
+        assert!(!zipped.is_terminated());
+        assert_eq!(zipped.next().await, None);
+        assert!(zipped.is_terminated());
+        assert_eq!(zipped.next().await, None);
+        assert_eq!(right_polls.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn try_fused_when_right_ends_first() {
+        let (left, left_polls) = counting_polls(stream::iter([Ok::<_, ()>(1), Ok(2), Ok(3)]));
+        let (right, right_polls) = counting_polls(stream::iter([Ok('a')]));
+        let mut zipped = left.try_zip_biased(right);
+
+        assert_eq!(zipped.next().await, Some(Ok((1, 'a'))));
+        assert_eq!(zipped.next().await, None);
+        assert!(zipped.is_terminated());
+        assert_eq!(zipped.next().await, None);
+        assert_eq!(left_polls.load(Ordering::Relaxed), 2);
+        assert_eq!(right_polls.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn size_hint_of_equal_lengths() {
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .zip_biased(stream::iter(['a', 'b', 'c']))
+                .size_hint(),
+            (3, Some(3))
+        );
+    }
+
+    #[test]
+    fn size_hint_of_a_shorter_left() {
+        assert_eq!(
+            stream::iter([1, 2])
+                .zip_biased(stream::iter(['a', 'b', 'c']))
+                .size_hint(),
+            (2, Some(2))
+        );
+        assert_eq!(
+            stream::iter([1, 2])
+                .zip_biased(stream::repeat('a'))
+                .size_hint(),
+            (2, Some(2))
+        );
+    }
+
+    #[test]
+    fn size_hint_of_a_shorter_right() {
+        assert_eq!(
+            stream::iter([1, 2, 3])
+                .zip_biased(stream::iter(['a']))
+                .size_hint(),
+            (1, Some(1))
+        );
+        assert_eq!(
+            stream::repeat(1)
+                .zip_biased(stream::iter(['a']))
+                .size_hint(),
+            (1, Some(1))
+        );
+    }
+
+    #[tokio::test]
+    async fn size_hint_counts_the_buffered_left_item() {
+        let (tx, rx) = mpsc::unbounded::<char>();
+        let mut zipped = stream::iter([1, 2, 3]).zip_biased(rx);
+
+        assert_eq!(poll!(zipped.next()), Poll::Pending);
+        assert_eq!(zipped.buffered_left(), Some(&1));
+        assert_eq!(zipped.size_hint(), (0, Some(3)));
+
+        tx.unbounded_send('a').unwrap();
+        drop(tx);
+        assert_eq!(zipped.next().await, Some((1, 'a')));
+        assert_eq!(zipped.next().await, None);
+        assert_eq!(zipped.size_hint(), (0, Some(0)));
+    }
 }
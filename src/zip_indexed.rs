@@ -0,0 +1,128 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+pub trait ZipIndexedStreamExt: Stream + Sized {
+    /// Pair each item with the element of `table` at the item's index.
+    ///
+    /// Past the end of the table, `mode` decides which element is used. An empty table ends the stream right away.
+    fn zip_indexed<T>(self, table: Vec<T>, mode: IndexMode) -> ZipIndexed<Self, T>
+    where
+        T: Clone,
+    {
+        ZipIndexed::new(self, table, mode)
+    }
+}
+
+/// How [`ZipIndexed`] handles the indices past the end of the table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexMode {
+    /// Start over from the first element.
+    Cycle,
+    /// Keep using the last element.
+    Clamp,
+    /// End the stream.
+    Stop,
+}
+
+/// Stream for [`zip_indexed`](`ZipIndexedStreamExt::zip_indexed`) method.
+#[derive(Debug, Clone)]
+#[pin_project::pin_project]
+pub struct ZipIndexed<Stream, T> {
+    #[pin]
+    inner: Stream,
+    table: Vec<T>,
+    mode: IndexMode,
+
+    index: usize,
+}
+
+impl<S, T> ZipIndexed<S, T> {
+    pub fn new(inner: S, table: Vec<T>, mode: IndexMode) -> Self {
+        Self {
+            inner,
+            table,
+            mode,
+            index: 0,
+        }
+    }
+}
+
+impl<S, T> Stream for ZipIndexed<S, T>
+where
+    S: Stream,
+    T: Clone,
+{
+    type Item = (S::Item, T);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::task::ready;
+
+        let this = self.project();
+
+        let len = this.table.len();
+        let at = match *this.mode {
+            _ if len == 0 => return Poll::Ready(None),
+            IndexMode::Cycle => *this.index % len,
+            IndexMode::Clamp => (*this.index).min(len - 1),
+            IndexMode::Stop if *this.index >= len => return Poll::Ready(None),
+            IndexMode::Stop => *this.index,
+        };
+
+        let Some(item) = ready!(this.inner.poll_next(cx)) else {
+            return Poll::Ready(None);
+        };
+        *this.index += 1;
+
+        Poll::Ready(Some((item, this.table[at].clone())))
+    }
+}
+
+impl<S> ZipIndexedStreamExt for S where S: Stream + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    async fn zipped(mode: IndexMode) -> Vec<(i32, char)> {
+        stream::iter(0..5)
+            .zip_indexed(vec!['a', 'b'], mode)
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn cycle() {
+        assert_eq!(
+            zipped(IndexMode::Cycle).await,
+            vec![(0, 'a'), (1, 'b'), (2, 'a'), (3, 'b'), (4, 'a')]
+        );
+    }
+
+    #[tokio::test]
+    async fn clamp() {
+        assert_eq!(
+            zipped(IndexMode::Clamp).await,
+            vec![(0, 'a'), (1, 'b'), (2, 'b'), (3, 'b'), (4, 'b')]
+        );
+    }
+
+    #[tokio::test]
+    async fn stop() {
+        assert_eq!(zipped(IndexMode::Stop).await, vec![(0, 'a'), (1, 'b')]);
+    }
+
+    #[tokio::test]
+    async fn empty_table() {
+        assert!(stream::iter(0..5)
+            .zip_indexed(Vec::<()>::new(), IndexMode::Cycle)
+            .collect::<Vec<_>>()
+            .await
+            .is_empty());
+    }
+}